@@ -2,26 +2,130 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, format_ident};
 use syn::{parse_str, ItemStruct, Fields, FieldsNamed, Field, Type, Visibility, Ident};
 
-// Generate a simple DTO (Data Transfer Object) struct
-fn generate_dto(name: &str, fields: Vec<(&str, &str)>) -> TokenStream {
+// How to coerce an untyped string input (a query param, a CSV cell, a log
+// field) into a DTO field's real type.
+#[derive(Debug, Clone)]
+enum Conversion {
+    Bytes,
+    String,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+}
+
+// Generate a DTO (Data Transfer Object) struct, plus a
+// `TryFrom<HashMap<String, String>>` impl that parses each field out of
+// untyped string input according to its declared `Conversion`.
+fn generate_dto(name: &str, fields: Vec<(&str, &str, Conversion)>) -> TokenStream {
     // Create struct identifier
     let struct_ident = Ident::new(name, Span::call_site());
-    
-    // Create fields
-    let fields = fields.iter().map(|(name, ty)| {
+    let error_ident = format_ident!("{}ParseError", name);
+
+    // Create struct fields
+    let struct_fields = fields.iter().map(|(name, ty, _)| {
         let field_ident = Ident::new(name, Span::call_site());
         let field_type = parse_str::<Type>(ty).unwrap();
-        
+
         quote! {
             pub #field_ident: #field_type
         }
     });
-    
-    // Generate the struct
+
+    // For each field, a statement that removes and parses its raw string
+    // value, and the identifier to move into the struct literal below.
+    let field_parsers = fields.iter().map(|(name, ty, conversion)| {
+        let field_ident = Ident::new(name, Span::call_site());
+        let field_type = parse_str::<Type>(ty).unwrap();
+        let target_type = quote!(#field_type).to_string();
+
+        let parse_expr = match conversion {
+            Conversion::Bytes => quote! {
+                raw.into_bytes()
+            },
+            Conversion::String => quote! {
+                raw
+            },
+            Conversion::Integer => quote! {
+                raw.parse().map_err(|e| #error_ident::invalid(#name, #target_type, e))?
+            },
+            Conversion::Float => quote! {
+                raw.parse().map_err(|e| #error_ident::invalid(#name, #target_type, e))?
+            },
+            Conversion::Boolean => quote! {
+                raw.parse().map_err(|e| #error_ident::invalid(#name, #target_type, e))?
+            },
+            Conversion::Timestamp => quote! {
+                chrono::DateTime::parse_from_rfc3339(&raw)
+                    .map_err(|e| #error_ident::invalid(#name, #target_type, e))?
+            },
+            Conversion::TimestampFmt(fmt) => quote! {
+                chrono::DateTime::parse_from_str(&raw, #fmt)
+                    .map_err(|e| #error_ident::invalid(#name, #target_type, e))?
+            },
+        };
+
+        quote! {
+            let #field_ident: #field_type = {
+                let raw = fields.remove(#name).ok_or_else(|| #error_ident::missing(#name, #target_type))?;
+                #parse_expr
+            };
+        }
+    });
+
+    let field_idents = fields.iter().map(|(name, _, _)| Ident::new(name, Span::call_site()));
+
+    // Generate the struct, its parse-error type, and the conversion impl
     quote! {
         #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
         pub struct #struct_ident {
-            #(#fields),*
+            #(#struct_fields),*
+        }
+
+        #[derive(Debug)]
+        pub struct #error_ident {
+            pub field: String,
+            pub target_type: String,
+            pub reason: String,
+        }
+
+        impl #error_ident {
+            fn missing(field: &str, target_type: &str) -> Self {
+                Self {
+                    field: field.to_string(),
+                    target_type: target_type.to_string(),
+                    reason: "field missing".to_string(),
+                }
+            }
+
+            fn invalid(field: &str, target_type: &str, reason: impl std::fmt::Display) -> Self {
+                Self {
+                    field: field.to_string(),
+                    target_type: target_type.to_string(),
+                    reason: reason.to_string(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "failed to parse field '{}' as {}: {}", self.field, self.target_type, self.reason)
+            }
+        }
+
+        impl std::error::Error for #error_ident {}
+
+        impl std::convert::TryFrom<std::collections::HashMap<String, String>> for #struct_ident {
+            type Error = #error_ident;
+
+            fn try_from(mut fields: std::collections::HashMap<String, String>) -> Result<Self, Self::Error> {
+                #(#field_parsers)*
+
+                Ok(Self {
+                    #(#field_idents),*
+                })
+            }
         }
     }
 }
@@ -214,10 +318,11 @@ fn generate_app_structure(entity_types: Vec<&str>) -> TokenStream {
 fn main() {
     // Example 1: Generate a DTO
     let user_dto = generate_dto("UserDto", vec![
-        ("id", "uuid::Uuid"),
-        ("username", "String"),
-        ("email", "String"),
-        ("created_at", "chrono::DateTime<chrono::Utc>"),
+        ("id", "String", Conversion::String),
+        ("username", "String", Conversion::String),
+        ("email", "String", Conversion::String),
+        ("signup_count", "i64", Conversion::Integer),
+        ("created_at", "chrono::DateTime<chrono::FixedOffset>", Conversion::Timestamp),
     ]);
     
     println!("=== Generated DTO ===\n{}", user_dto);
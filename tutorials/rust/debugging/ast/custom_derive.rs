@@ -1,7 +1,113 @@
 // Note: This would typically be in a separate crate
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, format_ident};
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Field, Fields, Lit, Meta, Type};
+
+// Error returned by a `from_raw` constructor generated for a `#[convert = "..."]`
+// field that failed to parse from its string form.
+#[derive(Debug)]
+enum ConversionError {
+    InvalidField { field: String, target_type: String },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::InvalidField { field, target_type } => {
+                write!(f, "failed to convert field '{}' to {}", field, target_type)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+// The string-to-type conversion declared for a field via `#[convert = "..."]`.
+enum Conversion {
+    AsIs,
+    Parsed,
+    Timestamp,
+    TimestampTz,
+    TimestampFmt(String),
+    TimestampFmtTz(String),
+}
+
+// Read the `#[convert = "..."]` attribute off a field, falling back to
+// as-is for `String` fields and a `FromStr` parse for everything else.
+fn field_conversion(field: &Field) -> Conversion {
+    for attr in &field.attrs {
+        if !attr.path().is_ident("convert") {
+            continue;
+        }
+        let Meta::NameValue(nv) = &attr.meta else {
+            continue;
+        };
+        let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = &nv.value else {
+            continue;
+        };
+        let value = lit.value();
+        return match value.as_str() {
+            "bytes" | "string" => Conversion::AsIs,
+            "int" | "float" | "bool" => Conversion::Parsed,
+            "timestamp" => Conversion::Timestamp,
+            "timestamp_tz" => Conversion::TimestampTz,
+            other if other.starts_with("timestamp_fmt_tz:") => {
+                Conversion::TimestampFmtTz(other["timestamp_fmt_tz:".len()..].to_string())
+            },
+            other if other.starts_with("timestamp_fmt:") => {
+                Conversion::TimestampFmt(other["timestamp_fmt:".len()..].to_string())
+            },
+            _ => Conversion::Parsed,
+        };
+    }
+
+    if is_string_type(&field.ty) {
+        Conversion::AsIs
+    } else {
+        Conversion::Parsed
+    }
+}
+
+fn is_string_type(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        type_path.path.segments.last().map_or(false, |seg| seg.ident == "String")
+    } else {
+        false
+    }
+}
+
+// Generate the expression that converts `raw: &String` into `field_type`,
+// mapping any failure into a `ConversionError::InvalidField`.
+fn conversion_expr(conversion: &Conversion, field_name: &str, field_type: &Type) -> TokenStream {
+    let target_type = quote!(#field_type).to_string();
+    let err = quote! {
+        ConversionError::InvalidField {
+            field: #field_name.to_string(),
+            target_type: #target_type.to_string(),
+        }
+    };
+
+    match conversion {
+        Conversion::AsIs => quote! { raw.to_string() },
+        Conversion::Parsed => quote! {
+            raw.parse::<#field_type>().map_err(|_| #err)?
+        },
+        Conversion::Timestamp => quote! {
+            chrono::DateTime::parse_from_rfc3339(raw)
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .map_err(|_| #err)?
+        },
+        Conversion::TimestampTz => quote! {
+            chrono::DateTime::parse_from_rfc3339(raw).map_err(|_| #err)?
+        },
+        Conversion::TimestampFmt(fmt) => quote! {
+            chrono::NaiveDateTime::parse_from_str(raw, #fmt).map_err(|_| #err)?
+        },
+        Conversion::TimestampFmtTz(fmt) => quote! {
+            chrono::DateTime::parse_from_str(raw, #fmt).map_err(|_| #err)?
+        },
+    }
+}
 
 // Custom derive macro for generating common methods for structs
 fn derive_common_methods(input: DeriveInput) -> TokenStream {
@@ -115,21 +221,61 @@ fn derive_common_methods(input: DeriveInput) -> TokenStream {
             }
         }
     };
-    
+
+    // Generate a `from_raw` constructor that parses each field out of a
+    // `HashMap<String, String>`, honoring any `#[convert = "..."]` attribute.
+    let from_raw_method = match input.data {
+        Data::Struct(ref data) => {
+            match data.fields {
+                Fields::Named(ref fields) => {
+                    let field_inits = fields.named.iter().map(|field| {
+                        let field_name = field.ident.as_ref().unwrap();
+                        let field_name_str = field_name.to_string();
+                        let conversion = field_conversion(field);
+                        let convert_expr = conversion_expr(&conversion, &field_name_str, &field.ty);
+
+                        quote! {
+                            #field_name: {
+                                let raw = values.get(#field_name_str).ok_or_else(|| ConversionError::InvalidField {
+                                    field: #field_name_str.to_string(),
+                                    target_type: "<missing>".to_string(),
+                                })?;
+                                #convert_expr
+                            }
+                        }
+                    });
+
+                    quote! {
+                        pub fn from_raw(values: &std::collections::HashMap<String, String>) -> Result<Self, ConversionError> {
+                            Ok(Self {
+                                #(#field_inits),*
+                            })
+                        }
+                    }
+                },
+                _ => quote! {},
+            }
+        },
+        _ => quote! {},
+    };
+
     // Generate implementation
     let expanded = quote! {
         impl #impl_generics #name #ty_generics #where_clause {
             // Constructor
             #new_method
-            
+
             // Field accessors
             #methods
-            
+
             // Clone method
             #clone_method
+
+            // String-keyed constructor with per-field type conversion
+            #from_raw_method
         }
     };
-    
+
     expanded
 }
 
@@ -141,22 +287,27 @@ fn derive_common_methods(input: DeriveInput) -> TokenStream {
 // }
 
 fn main() {
-    // Example struct definition
+    // Example struct definition, with `#[convert = "..."]` on the fields
+    // that need parsing out of a string-keyed record (e.g. a log/event line)
     let input = r#"
         struct User {
+            #[convert = "int"]
             id: u64,
             name: String,
             email: String,
+            #[convert = "bool"]
             active: bool,
+            #[convert = "timestamp"]
+            created_at: chrono::DateTime<chrono::Utc>,
         }
     "#;
-    
+
     // Parse the struct definition
     let derive_input = syn::parse_str::<DeriveInput>(input).unwrap();
-    
+
     // Generate the implementation
     let generated = derive_common_methods(derive_input);
-    
+
     // Print the generated code
     println!("// Generated implementation\n{}", generated);
 }
@@ -1,181 +1,639 @@
-use syn::{parse_file, Item, ItemFn, Expr, ExprMatch, Pat, Arm};
+// `LintError::new` below calls `Span::start()`/`end()` to report line/column
+// positions. Those methods only exist when proc-macro2's "span-locations"
+// feature is enabled in Cargo.toml - without it, this file is a hard compile
+// error (E0599: no method named `start`/`end` found for `proc_macro2::Span`),
+// not a silent (0, 0) fallback.
+use syn::{parse_file, ItemFn, Expr, ExprMatch, Pat, spanned::Spanned};
 use syn::visit::{self, Visit};
+use proc_macro2::Span;
+use rayon::prelude::*;
 use std::fs::File;
 use std::io::Read;
-use std::path::Path;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
-// Linting rule struct
-struct LintRule {
-    name: String,
-    description: String,
-    check_fn: fn(&syn::File) -> Vec<LintError>,
+// How seriously a diagnostic should be taken. `Allow` drops the rule from
+// the active set entirely rather than just muting its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Error,
+    #[serde(alias = "warn")]
+    Warning,
+    Info,
+    Allow,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+            Severity::Allow => "allow",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+// Deserialized from a `lint.toml` such as:
+//   [rules]
+//   unwrap_used = "warn"
+//   exhaustive_match = "error"
+//   [complex_function]
+//   max_statements = 30
+#[derive(serde::Deserialize, Default)]
+struct LintConfig {
+    #[serde(default)]
+    rules: HashMap<String, Severity>,
+    #[serde(default)]
+    complex_function: ComplexFunctionConfig,
+    #[serde(default)]
+    file_size: FileSizeConfig,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct ComplexFunctionConfig {
+    max_statements: usize,
+}
+
+impl Default for ComplexFunctionConfig {
+    fn default() -> Self {
+        ComplexFunctionConfig { max_statements: 20 }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct FileSizeConfig {
+    max_bytes: u64,
+}
+
+impl Default for FileSizeConfig {
+    fn default() -> Self {
+        FileSizeConfig { max_bytes: 100_000 }
+    }
+}
+
+// Converts a (1-indexed line, 0-indexed column) pair, as reported by
+// `proc_macro2::LineColumn`, into a byte offset into `source`. Columns are
+// counted in chars, matching how `LineColumn` counts them.
+fn line_col_to_offset(source: &str, line: usize, column: usize) -> usize {
+    let mut offset = 0;
+    for (i, text) in source.split('\n').enumerate() {
+        if i + 1 == line {
+            let byte_col = text.char_indices().nth(column).map(|(b, _)| b).unwrap_or(text.len());
+            return offset + byte_col;
+        }
+        offset += text.len() + 1; // +1 for the '\n' stripped by split
+    }
+    source.len()
+}
+
+// A single text edit: delete the byte range `delete` and splice in `insert`.
+#[derive(Clone)]
+struct Indel {
+    delete: Range<usize>,
+    insert: String,
+}
+
+// One or more edits that together apply a rule's suggested fix.
+#[derive(Clone)]
+struct Fix {
+    indels: Vec<Indel>,
+}
+
+// Applies batches of `Indel`s to a source string.
+struct Fixer;
+
+impl Fixer {
+    // Splices `indels` into `source`, applied back-to-front (descending by
+    // start offset) so that earlier offsets stay valid as later ones are
+    // spliced in. Rejects overlapping ranges rather than guessing precedence.
+    fn apply(source: &str, mut indels: Vec<Indel>) -> Result<String, String> {
+        indels.sort_by(|a, b| b.delete.start.cmp(&a.delete.start));
+
+        for pair in indels.windows(2) {
+            let (later, earlier) = (&pair[0], &pair[1]);
+            if later.delete.start < earlier.delete.end {
+                return Err(format!(
+                    "overlapping fixes at {:?} and {:?}",
+                    earlier.delete, later.delete
+                ));
+            }
+        }
+
+        let mut result = source.to_string();
+        for indel in &indels {
+            result.replace_range(indel.delete.clone(), &indel.insert);
+        }
+        Ok(result)
+    }
 }
 
 // Error reported by a lint rule
+#[derive(Clone)]
 struct LintError {
     message: String,
+    // Lines 1-indexed, columns 0-indexed, matching proc_macro2::LineColumn
     line: usize,
     column: usize,
+    end_line: usize,
+    end_column: usize,
     rule_name: String,
+    file: PathBuf,
+    // Filled in by `RustLinter::lint_file` from the rule's configured
+    // severity; rules themselves don't know how they've been configured.
+    severity: Severity,
 }
 
 impl LintError {
-    fn new(message: String, rule_name: &str) -> Self {
+    fn new(message: String, rule_name: &str, span: Span) -> Self {
+        let start = span.start();
+        let end = span.end();
         LintError {
             message,
-            line: 0,  // Would be populated from span in a real implementation
-            column: 0, // Would be populated from span in a real implementation
+            line: start.line,
+            column: start.column,
+            end_line: end.line,
+            end_column: end.column,
             rule_name: rule_name.to_string(),
+            file: PathBuf::new(),
+            severity: Severity::Warning,
+        }
+    }
+
+    // Render this error the way rustc annotates a diagnostic: the offending
+    // line followed by a caret-underline spanning the reported columns.
+    fn render(&self, source: &str) -> String {
+        let source_line = source.lines().nth(self.line - 1).unwrap_or("");
+
+        // Multi-line spans only get carets under their first line; the rest
+        // of the span is implied by the line/column range in the header.
+        let underline_end = if self.end_line == self.line {
+            self.end_column
+        } else {
+            source_line.len()
+        };
+        let underline_start = self.column.min(underline_end);
+        let caret_count = (underline_end - underline_start).max(1);
+
+        format!(
+            "error[{}]: {}\n  --> {}:{}:{}\n   |\n{:>3}| {}\n   | {}{}\n   |",
+            self.rule_name,
+            self.message,
+            self.file.display(),
+            self.line,
+            self.column + 1,
+            self.line,
+            source_line,
+            " ".repeat(underline_start),
+            "^".repeat(caret_count),
+        )
+    }
+}
+
+// Everything a rule needs to inspect a single file: the parsed AST, the
+// original source (for snippet rendering), the path it came from, and its
+// filesystem metadata. AST-based rules use `syntax`/`source`; "tidy" rules
+// (file size, executable bit) only need `path`/`metadata`.
+struct RuleContext<'a> {
+    path: &'a Path,
+    source: &'a str,
+    syntax: &'a syn::File,
+    metadata: &'a std::fs::Metadata,
+}
+
+// A lint rule. Unlike a bare `fn` pointer, a trait object can carry its own
+// state (thresholds, config) and is `Send + Sync` so the linter can fan
+// files out across a thread pool (each file's rules run in sequence).
+trait Rule: Send + Sync {
+    fn name(&self) -> &str;
+    fn description(&self) -> &str;
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)>;
+}
+
+// Rule implementation: Check for non-exhaustive match expressions
+struct ExhaustiveMatchRule;
+
+impl Rule for ExhaustiveMatchRule {
+    fn name(&self) -> &str {
+        "exhaustive_match"
+    }
+
+    fn description(&self) -> &str {
+        "Checks for non-exhaustive match expressions"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)> {
+        struct MatchVisitor<'s> {
+            source: &'s str,
+            findings: Vec<(LintError, Option<Fix>)>,
+        }
+
+        impl<'ast, 's> Visit<'ast> for MatchVisitor<'s> {
+            fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
+                // Check if the match has a wildcard pattern
+                let has_wildcard = node.arms.iter().any(|arm| {
+                    matches!(arm.pat, Pat::Wild(_))
+                });
+
+                // If it doesn't have a wildcard, it might not be exhaustive
+                if !has_wildcard {
+                    let error = LintError::new(
+                        "Match expression might not be exhaustive. Consider adding a wildcard '_' pattern".to_string(),
+                        "exhaustive_match",
+                        node.span(),
+                    );
+
+                    // `node.span().end()` is the byte just past the match's closing
+                    // brace; step back one to insert the arm right before it.
+                    let end = node.span().end();
+                    let insert_at = line_col_to_offset(self.source, end.line, end.column).saturating_sub(1);
+                    let fix = Fix {
+                        indels: vec![Indel {
+                            delete: insert_at..insert_at,
+                            insert: "_ => todo!(),".to_string(),
+                        }],
+                    };
+
+                    self.findings.push((error, Some(fix)));
+                }
+
+                // Continue visiting
+                visit::visit_expr_match(self, node);
+            }
+        }
+
+        let mut visitor = MatchVisitor { source: ctx.source, findings: Vec::new() };
+        visitor.visit_file(ctx.syntax);
+        visitor.findings
+    }
+}
+
+// Rule implementation: Check for unwrap() usage
+struct UnwrapUsedRule;
+
+impl Rule for UnwrapUsedRule {
+    fn name(&self) -> &str {
+        "unwrap_used"
+    }
+
+    fn description(&self) -> &str {
+        "Detects usage of unwrap() which might panic"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)> {
+        struct UnwrapVisitor<'s> {
+            source: &'s str,
+            findings: Vec<(LintError, Option<Fix>)>,
+        }
+
+        impl<'ast, 's> Visit<'ast> for UnwrapVisitor<'s> {
+            fn visit_expr(&mut self, node: &'ast Expr) {
+                // Look for method calls
+                if let Expr::MethodCall(method_call) = node {
+                    // Check if method name is unwrap
+                    if method_call.method == "unwrap" {
+                        let error = LintError::new(
+                            "Use of unwrap() detected. Consider using ? or match/if let for error handling".to_string(),
+                            "unwrap_used",
+                            method_call.method.span(),
+                        );
+
+                        // Replace `.unwrap()` (from the dot to the closing paren) with `.expect("TODO")`.
+                        let dot_start = method_call.dot_token.span().start();
+                        let call_end = node.span().end();
+                        let delete = line_col_to_offset(self.source, dot_start.line, dot_start.column)
+                            ..line_col_to_offset(self.source, call_end.line, call_end.column);
+                        let fix = Fix {
+                            indels: vec![Indel {
+                                delete,
+                                insert: ".expect(\"TODO\")".to_string(),
+                            }],
+                        };
+
+                        self.findings.push((error, Some(fix)));
+                    }
+                }
+
+                // Continue visiting
+                visit::visit_expr(self, node);
+            }
+        }
+
+        let mut visitor = UnwrapVisitor { source: ctx.source, findings: Vec::new() };
+        visitor.visit_file(ctx.syntax);
+        visitor.findings
+    }
+}
+
+// Rule implementation: Check for complex functions
+struct ComplexFunctionRule {
+    // Configurable, unlike the old `fn`-pointer rule which hardcoded this.
+    max_statements: usize,
+}
+
+impl Rule for ComplexFunctionRule {
+    fn name(&self) -> &str {
+        "complex_function"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies functions that are too large or complex"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)> {
+        struct ComplexityVisitor {
+            max_statements: usize,
+            findings: Vec<(LintError, Option<Fix>)>,
+        }
+
+        impl<'ast> Visit<'ast> for ComplexityVisitor {
+            fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+                // Simple complexity metric: count statements
+                let stmt_count = node.block.stmts.len();
+
+                // If function has too many statements, report it
+                if stmt_count > self.max_statements {
+                    let error = LintError::new(
+                        format!("Function '{}' has {} statements, which exceeds the recommended maximum of {}",
+                                node.sig.ident, stmt_count, self.max_statements),
+                        "complex_function",
+                        node.sig.span(),
+                    );
+
+                    // No automatic fix: splitting up a function is a design
+                    // decision, not a mechanical edit.
+                    self.findings.push((error, None));
+                }
+
+                // Continue visiting
+                visit::visit_item_fn(self, node);
+            }
+        }
+
+        let mut visitor = ComplexityVisitor {
+            max_statements: self.max_statements,
+            findings: Vec::new(),
+        };
+        visitor.visit_file(ctx.syntax);
+        visitor.findings
+    }
+}
+
+// "Tidy" rule: flag `.rs` files with the executable bit set, the usual sign
+// of an accidentally-committed executable. Operates on filesystem metadata
+// rather than the parsed AST.
+struct ExecutableBitRule;
+
+impl Rule for ExecutableBitRule {
+    fn name(&self) -> &str {
+        "executable_rs_file"
+    }
+
+    fn description(&self) -> &str {
+        "Flags .rs files that have the executable bit set"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if ctx.metadata.permissions().mode() & 0o111 != 0 {
+                let error = LintError::new(
+                    format!("'{}' is a .rs file with the executable bit set", ctx.path.display()),
+                    "executable_rs_file",
+                    Span::call_site(),
+                );
+                return vec![(error, None)];
+            }
+        }
+        Vec::new()
+    }
+}
+
+// "Tidy" rule: flag source files over a configurable byte size. Also
+// metadata-only, no AST involved.
+struct FileSizeRule {
+    max_bytes: u64,
+}
+
+impl Rule for FileSizeRule {
+    fn name(&self) -> &str {
+        "file_too_large"
+    }
+
+    fn description(&self) -> &str {
+        "Flags source files exceeding a configurable byte size"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<(LintError, Option<Fix>)> {
+        let size = ctx.metadata.len();
+        if size > self.max_bytes {
+            let error = LintError::new(
+                format!("'{}' is {} bytes, exceeding the {} byte limit", ctx.path.display(), size, self.max_bytes),
+                "file_too_large",
+                Span::call_site(),
+            );
+            vec![(error, None)]
+        } else {
+            Vec::new()
         }
     }
 }
 
 // Custom linter implementation
 struct RustLinter {
-    rules: Vec<LintRule>,
+    rules: Vec<(Box<dyn Rule + Send + Sync>, Severity)>,
 }
 
 impl RustLinter {
     fn new() -> Self {
-        // Create a linter with default rules
+        // Create a linter with default rules, all at the default severity
         let mut linter = RustLinter {
             rules: Vec::new(),
         };
-        
-        // Add default rules
-        linter.add_rule(LintRule {
-            name: "exhaustive_match".to_string(),
-            description: "Checks for non-exhaustive match expressions".to_string(),
-            check_fn: check_exhaustive_match,
-        });
-        
-        linter.add_rule(LintRule {
-            name: "unwrap_used".to_string(),
-            description: "Detects usage of unwrap() which might panic".to_string(),
-            check_fn: check_unwrap_usage,
-        });
-        
-        linter.add_rule(LintRule {
-            name: "complex_function".to_string(),
-            description: "Identifies functions that are too large or complex".to_string(),
-            check_fn: check_complex_functions,
-        });
-        
+
+        linter.add_rule(Box::new(ExhaustiveMatchRule), Severity::Warning);
+        linter.add_rule(Box::new(UnwrapUsedRule), Severity::Warning);
+        linter.add_rule(Box::new(ComplexFunctionRule { max_statements: 20 }), Severity::Warning);
+        linter.add_rule(Box::new(ExecutableBitRule), Severity::Warning);
+        linter.add_rule(Box::new(FileSizeRule { max_bytes: 100_000 }), Severity::Warning);
+
         linter
     }
-    
-    fn add_rule(&mut self, rule: LintRule) {
-        self.rules.push(rule);
+
+    // Loads rule severities, and the `complex_function` threshold, from a
+    // TOML config file. Rules configured as `"allow"` are dropped from the
+    // active set rather than just having their output suppressed; every
+    // other rule defaults to `Severity::Warning` if the config omits it.
+    fn from_config(config_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(config_path)?;
+        let config: LintConfig = toml::from_str(&text)?;
+
+        let all_rules: Vec<Box<dyn Rule + Send + Sync>> = vec![
+            Box::new(ExhaustiveMatchRule),
+            Box::new(UnwrapUsedRule),
+            Box::new(ComplexFunctionRule { max_statements: config.complex_function.max_statements }),
+            Box::new(ExecutableBitRule),
+            Box::new(FileSizeRule { max_bytes: config.file_size.max_bytes }),
+        ];
+
+        let mut linter = RustLinter { rules: Vec::new() };
+        for rule in all_rules {
+            let severity = config.rules.get(rule.name()).copied().unwrap_or(Severity::Warning);
+            if severity != Severity::Allow {
+                linter.add_rule(rule, severity);
+            }
+        }
+
+        Ok(linter)
+    }
+
+    fn add_rule(&mut self, rule: Box<dyn Rule + Send + Sync>, severity: Severity) {
+        self.rules.push((rule, severity));
     }
-    
-    fn lint_file(&self, path: &Path) -> Result<Vec<LintError>, Box<dyn std::error::Error>> {
+
+    // Lint a single file, returning its source (for snippet rendering) and
+    // each diagnostic paired with its optional fix, sorted by line/column.
+    fn lint_file(&self, path: &Path) -> Result<(String, Vec<(LintError, Option<Fix>)>), Box<dyn std::error::Error>> {
         // Read the file
         let mut file = File::open(path)?;
-        let mut content = String::new();
-        file.read_to_string(&mut content)?;
-        
+        let mut source = String::new();
+        file.read_to_string(&mut source)?;
+
+        // `symlink_metadata` rather than `metadata`, so a symlinked `.rs`
+        // file is reported on its own terms rather than its target's.
+        let metadata = std::fs::symlink_metadata(path)?;
+
         // Parse the file
-        let syntax = parse_file(&content)?;
-        
-        // Apply all rules
-        let mut errors = Vec::new();
-        for rule in &self.rules {
-            let rule_errors = (rule.check_fn)(&syntax);
-            errors.extend(rule_errors);
-        }
-        
-        Ok(errors)
+        let syntax = parse_file(&source)?;
+        let ctx = RuleContext { path, source: &source, syntax: &syntax, metadata: &metadata };
+
+        // Apply all rules, stamping each diagnostic with the file and
+        // severity it came from
+        let mut findings: Vec<(LintError, Option<Fix>)> = self.rules
+            .iter()
+            .flat_map(|(rule, severity)| {
+                rule.check(&ctx).into_iter().map(move |(mut error, fix)| {
+                    error.file = path.to_path_buf();
+                    error.severity = *severity;
+                    (error, fix)
+                })
+            })
+            .collect();
+
+        findings.sort_by_key(|(e, _)| (e.file.clone(), e.line, e.column));
+
+        Ok((source, findings))
     }
-}
 
-// Rule implementation: Check for non-exhaustive match expressions
-fn check_exhaustive_match(file: &syn::File) -> Vec<LintError> {
-    struct MatchVisitor {
-        errors: Vec<LintError>,
-    }
-    
-    impl<'ast> Visit<'ast> for MatchVisitor {
-        fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
-            // Check if the match has a wildcard pattern
-            let has_wildcard = node.arms.iter().any(|arm| {
-                matches!(arm.pat, Pat::Wild(_))
-            });
-            
-            // If it doesn't have a wildcard, it might not be exhaustive
-            if !has_wildcard {
-                self.errors.push(LintError::new(
-                    "Match expression might not be exhaustive. Consider adding a wildcard '_' pattern".to_string(),
-                    "exhaustive_match"
-                ));
+    // Lint many files at once. Rules are `Send + Sync` and each file's
+    // syntax tree is independent, so files are fanned out across rayon's
+    // thread pool; diagnostics are then merged and sorted deterministically
+    // by file, then line, then column, regardless of completion order.
+    fn lint_files(&self, paths: &[PathBuf]) -> Vec<(PathBuf, String, LintError)> {
+        let mut results: Vec<(PathBuf, String, LintError)> = paths
+            .par_iter()
+            .filter_map(|path| self.lint_file(path).ok().map(|(source, findings)| (path, source, findings)))
+            .flat_map(|(path, source, findings)| {
+                findings
+                    .into_iter()
+                    .map(|(error, _fix)| (path.clone(), source.clone(), error))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        results.sort_by(|a, b| (&a.0, a.2.line, a.2.column).cmp(&(&b.0, b.2.line, b.2.column)));
+        results
+    }
+
+    // Lints `path`, printing every diagnostic with a `[severity]` prefix.
+    // When `apply` is true, the suggested fixes (those rules returned one
+    // for) are spliced into the source and written back to the file.
+    // Returns `true` if any `Error`-level diagnostic fired, so callers can
+    // use it as a nonzero-exit-code signal.
+    fn lint_file_and_fix(&self, path: &Path, apply: bool) -> Result<bool, Box<dyn std::error::Error>> {
+        let (source, findings) = self.lint_file(path)?;
+
+        println!("Found {} lint issues:", findings.len());
+        let mut has_error = false;
+        for (error, fix) in &findings {
+            has_error |= error.severity == Severity::Error;
+            println!("[{}] {}", error.severity, error.render(&source));
+            if let Some(fix) = fix {
+                println!("  fix available ({} edit(s))", fix.indels.len());
+            }
+            println!();
+        }
+
+        if apply {
+            let indels: Vec<Indel> = findings
+                .into_iter()
+                .filter_map(|(_, fix)| fix)
+                .flat_map(|fix| fix.indels)
+                .collect();
+
+            if !indels.is_empty() {
+                let fixed = Fixer::apply(&source, indels)?;
+                std::fs::write(path, fixed)?;
             }
-            
-            // Continue visiting
-            visit::visit_expr_match(self, node);
         }
+
+        Ok(has_error)
     }
-    
-    let mut visitor = MatchVisitor { errors: Vec::new() };
-    visitor.visit_file(file);
-    visitor.errors
-}
 
-// Rule implementation: Check for unwrap() usage
-fn check_unwrap_usage(file: &syn::File) -> Vec<LintError> {
-    struct UnwrapVisitor {
-        errors: Vec<LintError>,
-    }
-    
-    impl<'ast> Visit<'ast> for UnwrapVisitor {
-        fn visit_expr(&mut self, node: &'ast Expr) {
-            // Look for method calls
-            if let Expr::MethodCall(method_call) = node {
-                // Check if method name is unwrap
-                if method_call.method == "unwrap" {
-                    self.errors.push(LintError::new(
-                        "Use of unwrap() detected. Consider using ? or match/if let for error handling".to_string(),
-                        "unwrap_used"
-                    ));
-                }
+    // Directories that typically hold build output or vendored/third-party
+    // code; their contents aren't worth linting.
+    const SKIP_DIRS: &'static [&'static str] = &["target", ".git", "node_modules", "vendor", ".cargo"];
+
+    // Recursively walks `root`, skipping `Self::SKIP_DIRS`, lints every
+    // `.rs` file it finds (in parallel, via `lint_files`), and groups the
+    // resulting diagnostics by the file they came from.
+    fn lint_directory(&self, root: &Path) -> Vec<(PathBuf, Vec<LintError>)> {
+        let mut rs_files = Vec::new();
+        Self::collect_rs_files(root, &mut rs_files);
+        rs_files.sort();
+
+        // Seed one entry per scanned file first, so a clean file (zero
+        // findings) still shows up with an empty list - `lint_files` only
+        // yields a tuple per *finding*, so a fold starting from its output
+        // alone would silently drop clean files.
+        let mut by_file: Vec<(PathBuf, Vec<LintError>)> =
+            rs_files.iter().map(|path| (path.clone(), Vec::new())).collect();
+
+        for (path, _source, error) in self.lint_files(&rs_files) {
+            if let Some((_, errors)) = by_file.iter_mut().find(|(p, _)| *p == path) {
+                errors.push(error);
             }
-            
-            // Continue visiting
-            visit::visit_expr(self, node);
         }
+        by_file
     }
-    
-    let mut visitor = UnwrapVisitor { errors: Vec::new() };
-    visitor.visit_file(file);
-    visitor.errors
-}
 
-// Rule implementation: Check for complex functions
-fn check_complex_functions(file: &syn::File) -> Vec<LintError> {
-    struct ComplexityVisitor {
-        errors: Vec<LintError>,
-    }
-    
-    impl<'ast> Visit<'ast> for ComplexityVisitor {
-        fn visit_item_fn(&mut self, node: &'ast ItemFn) {
-            // Simple complexity metric: count statements
-            let stmt_count = node.block.stmts.len();
-            
-            // If function has too many statements, report it
-            if stmt_count > 20 {  // Arbitrary threshold
-                self.errors.push(LintError::new(
-                    format!("Function '{}' has {} statements, which exceeds the recommended maximum of 20", 
-                            node.sig.ident, stmt_count),
-                    "complex_function"
-                ));
+    fn collect_rs_files(dir: &Path, out: &mut Vec<PathBuf>) {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let skipped = path.file_name()
+                    .and_then(|name| name.to_str())
+                    .map(|name| Self::SKIP_DIRS.contains(&name))
+                    .unwrap_or(false);
+                if !skipped {
+                    Self::collect_rs_files(&path, out);
+                }
+            } else if path.extension().and_then(|ext| ext.to_str()) == Some("rs") {
+                out.push(path);
             }
-            
-            // Continue visiting
-            visit::visit_item_fn(self, node);
         }
     }
-    
-    let mut visitor = ComplexityVisitor { errors: Vec::new() };
-    visitor.visit_file(file);
-    visitor.errors
 }
 
 fn main() {
@@ -184,20 +642,20 @@ fn main() {
     fn process_data(data: Option<String>) -> String {
         // Using unwrap which might panic
         let value = data.unwrap();
-        
+
         // Non-exhaustive match
         let result = match value.as_str() {
             "hello" => "world",
             "goodbye" => "friend",
             // Missing wildcard case
         };
-        
+
         result.to_string()
     }
-    
+
     fn very_complex_function() {
         let mut sum = 0;
-        
+
         // Lots of statements to trigger complexity warning
         for i in 0..100 {
             sum += i;
@@ -220,25 +678,56 @@ fn main() {
         println!("Final sum: {}", sum);
     }
     "#;
-    
+
     // Write to a temporary file for linting
     let temp_file = "temp_lint_example.rs";
     std::fs::write(temp_file, example_code).expect("Failed to write temporary file");
-    
-    // Create a linter and run it
-    let linter = RustLinter::new();
+
+    // Example config: promote `unwrap_used` to a warning, `exhaustive_match`
+    // to a hard error, and raise the complexity threshold.
+    let config_file = "temp_lint.toml";
+    std::fs::write(
+        config_file,
+        r#"
+        [rules]
+        unwrap_used = "warn"
+        exhaustive_match = "error"
+
+        [complex_function]
+        max_statements = 30
+        "#,
+    ).expect("Failed to write temporary config file");
+
+    // Create a linter from the config and run it
+    let linter = RustLinter::from_config(Path::new(config_file)).expect("Failed to load lint config");
     let path = Path::new(temp_file);
-    
-    match linter.lint_file(path) {
-        Ok(errors) => {
-            println!("Found {} lint issues:", errors.len());
-            for error in errors {
-                println!("[{}] {}", error.rule_name, error.message);
-            }
-        },
-        Err(e) => eprintln!("Error linting file: {}", e),
-    }
-    
+
+    let exit_code = match linter.lint_file_and_fix(path, true) {
+        Ok(has_error) => i32::from(has_error),
+        Err(e) => {
+            eprintln!("Error linting file: {}", e);
+            1
+        }
+    };
+
     // Clean up
     std::fs::remove_file(temp_file).expect("Failed to remove temporary file");
+    std::fs::remove_file(config_file).expect("Failed to remove temporary config file");
+
+    // Demonstrate directory-wide linting, including the tidy checks, over a
+    // small throwaway source tree.
+    let temp_dir = Path::new("temp_lint_dir");
+    std::fs::create_dir_all(temp_dir.join("src")).expect("Failed to create temporary directory");
+    std::fs::write(temp_dir.join("src").join("lib.rs"), "fn add(a: i32, b: i32) -> i32 { a + b }\n")
+        .expect("Failed to write temporary file");
+
+    let default_linter = RustLinter::new();
+    println!("Directory lint summary for '{}':", temp_dir.display());
+    for (file, errors) in default_linter.lint_directory(temp_dir) {
+        println!("  {}: {} issue(s)", file.display(), errors.len());
+    }
+
+    std::fs::remove_dir_all(temp_dir).expect("Failed to remove temporary directory");
+
+    std::process::exit(exit_code);
 }
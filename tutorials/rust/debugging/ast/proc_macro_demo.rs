@@ -1,30 +1,97 @@
 // Note: This would typically be in a separate proc-macro crate
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, ItemFn, AttributeArgs, NestedMeta, Lit, Meta};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, ExprLit, ItemFn, Lit, Meta, Path, Token};
+
+// Backoff strategy for the retry wrapper
+enum Backoff {
+    Fixed,
+    Exponential,
+}
+
+// Pulls the integer out of a `key = 123`-style `Meta::NameValue`, if its
+// value is an integer literal.
+fn name_value_int<N: std::str::FromStr>(nv: &syn::MetaNameValue) -> Option<N>
+where
+    N::Err: std::fmt::Display,
+{
+    match &nv.value {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+// Pulls the string out of a `key = "..."`-style `Meta::NameValue`, if its
+// value is a string literal.
+fn name_value_str(nv: &syn::MetaNameValue) -> Option<String> {
+    match &nv.value {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(lit.value()),
+        _ => None,
+    }
+}
 
 // Procedural macro for adding retry logic to functions
-fn retry_macro(args: AttributeArgs, input: ItemFn) -> TokenStream {
+fn retry_macro(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> TokenStream {
     // Parse macro arguments
-    let mut max_retries = 3; // Default
-    let mut delay_ms = 100;  // Default
-    
+    let mut max_retries: u32 = 3; // Default
+    let mut delay_ms: u64 = 100;  // Default base delay
+    let mut max_delay_ms: u64 = 5_000; // Default cap for exponential backoff
+    let mut backoff = Backoff::Fixed;
+    let mut jitter = false;
+    let mut deadline_ms: Option<u64> = None;
+    let mut retry_if: Option<Path> = None;
+
     for arg in args {
         match arg {
-            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("retries") => {
-                if let Lit::Int(lit) = nv.lit {
-                    max_retries = lit.base10_parse().unwrap_or(3);
+            Meta::NameValue(nv) if nv.path.is_ident("retries") => {
+                if let Some(value) = name_value_int(&nv) {
+                    max_retries = value;
+                }
+            },
+            Meta::NameValue(nv) if nv.path.is_ident("delay") => {
+                if let Some(value) = name_value_int(&nv) {
+                    delay_ms = value;
+                }
+            },
+            Meta::NameValue(nv) if nv.path.is_ident("max_delay") => {
+                if let Some(value) = name_value_int(&nv) {
+                    max_delay_ms = value;
+                }
+            },
+            Meta::NameValue(nv) if nv.path.is_ident("deadline_ms") => {
+                deadline_ms = name_value_int(&nv);
+            },
+            Meta::NameValue(nv) if nv.path.is_ident("backoff") => {
+                if let Some(value) = name_value_str(&nv) {
+                    backoff = match value.as_str() {
+                        "exponential" => Backoff::Exponential,
+                        _ => Backoff::Fixed,
+                    };
                 }
             },
-            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("delay") => {
-                if let Lit::Int(lit) = nv.lit {
-                    delay_ms = lit.base10_parse().unwrap_or(100);
+            Meta::NameValue(nv) if nv.path.is_ident("jitter") => {
+                if let Expr::Lit(ExprLit { lit: Lit::Bool(lit), .. }) = &nv.value {
+                    jitter = lit.value;
                 }
             },
+            Meta::Path(path) if path.is_ident("jitter") => {
+                jitter = true;
+            },
+            // `retry_if` may be a bare path (`retry_if = is_transient`) or a
+            // quoted one (`retry_if = "is_transient"`); accept either.
+            Meta::NameValue(nv) if nv.path.is_ident("retry_if") => {
+                retry_if = match &nv.value {
+                    Expr::Path(expr_path) => Some(expr_path.path.clone()),
+                    Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => lit.parse::<Path>().ok(),
+                    _ => None,
+                };
+            },
             _ => {},
         }
     }
-    
+
     // Get function details
     let fn_vis = &input.vis;
     let fn_sig = &input.sig;
@@ -51,40 +118,103 @@ fn retry_macro(args: AttributeArgs, input: ItemFn) -> TokenStream {
     
     // Generate the wrapped function
     let is_async = fn_sig.asyncness.is_some();
-    
+
     let function_call = if is_async {
         quote! { #fn_name(#(#args),*).await }
     } else {
         quote! { #fn_name(#(#args),*) }
     };
-    
+
     // Original function with renamed
     let original_fn_name = syn::Ident::new(
         &format!("__original_{}", fn_name),
         proc_macro2::Span::call_site()
     );
-    
+
     let original_fn = quote! {
         #fn_vis fn #original_fn_name #fn_generics(#fn_inputs) #fn_output #fn_block
     };
-    
+
+    // Delay for attempt `n` (1-based): fixed, or exponential capped at max_delay_ms
+    let delay_expr = match backoff {
+        Backoff::Fixed => quote! { #delay_ms },
+        Backoff::Exponential => quote! {
+            (#delay_ms).saturating_mul(1u64 << (attempts - 1).min(63)).min(#max_delay_ms)
+        },
+    };
+
+    // Jitter: pick a uniformly random value in [0, delay) via a simple LCG seeded
+    // from the current time, so concurrent callers don't synchronize.
+    let jittered_delay_expr = if jitter {
+        quote! {
+            {
+                let base = #delay_expr;
+                if base == 0 {
+                    0
+                } else {
+                    let seed = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.subsec_nanos() as u64)
+                        .unwrap_or(attempts as u64)
+                        ^ (attempts as u64).wrapping_mul(0x9E3779B97F4A7C15);
+                    // Numerical Recipes LCG
+                    let lcg = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    lcg % base
+                }
+            }
+        }
+    } else {
+        delay_expr.clone()
+    };
+
+    // Only retry when `retry_if` (if configured) says the error is retryable
+    let retry_if_check = if let Some(predicate) = &retry_if {
+        quote! {
+            if !#predicate(&e) {
+                return Err(e);
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Bail out early, returning the last error, if the next sleep would blow the deadline
+    let deadline_setup = if deadline_ms.is_some() {
+        quote! { let __retry_deadline_start = std::time::Instant::now(); }
+    } else {
+        quote! {}
+    };
+    let deadline_check = if let Some(deadline) = deadline_ms {
+        quote! {
+            if __retry_deadline_start.elapsed() + Duration::from_millis(delay) > Duration::from_millis(#deadline) {
+                return Err(e);
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate retry wrapper function
     let wrapper_fn = if is_async {
         quote! {
             #fn_vis async fn #fn_name #fn_generics(#fn_inputs) #fn_output {
                 use std::time::Duration;
-                let mut attempts = 0;
+                #deadline_setup
+                let mut attempts: u32 = 0;
                 loop {
                     attempts += 1;
                     match #original_fn_name(#(#args),*).await {
                         Ok(result) => return Ok(result),
                         Err(e) => {
+                            #retry_if_check
                             if attempts >= #max_retries {
                                 return Err(e);
                             }
-                            eprintln!("Attempt {} failed, retrying in {} ms: {:?}", 
-                                     attempts, #delay_ms, e);
-                            tokio::time::sleep(Duration::from_millis(#delay_ms)).await;
+                            let delay = #jittered_delay_expr;
+                            #deadline_check
+                            eprintln!("Attempt {} failed, retrying in {} ms: {:?}",
+                                     attempts, delay, e);
+                            tokio::time::sleep(Duration::from_millis(delay)).await;
                         }
                     }
                 }
@@ -95,38 +225,42 @@ fn retry_macro(args: AttributeArgs, input: ItemFn) -> TokenStream {
             #fn_vis fn #fn_name #fn_generics(#fn_inputs) #fn_output {
                 use std::thread::sleep;
                 use std::time::Duration;
-                let mut attempts = 0;
+                #deadline_setup
+                let mut attempts: u32 = 0;
                 loop {
                     attempts += 1;
                     match #original_fn_name(#(#args),*) {
                         Ok(result) => return Ok(result),
                         Err(e) => {
+                            #retry_if_check
                             if attempts >= #max_retries {
                                 return Err(e);
                             }
-                            eprintln!("Attempt {} failed, retrying in {} ms: {:?}", 
-                                     attempts, #delay_ms, e);
-                            sleep(Duration::from_millis(#delay_ms));
+                            let delay = #jittered_delay_expr;
+                            #deadline_check
+                            eprintln!("Attempt {} failed, retrying in {} ms: {:?}",
+                                     attempts, delay, e);
+                            sleep(Duration::from_millis(delay));
                         }
                     }
                 }
             }
         }
     };
-    
+
     // Combine original and wrapper functions
     quote! {
         #original_fn
-        
+
         #wrapper_fn
     }
 }
 
 // This would be the actual proc macro in a real macro crate
 // #[proc_macro_attribute]
-// pub fn retry(args: proc_macro::TokenStream, input: proc_macro::TokenStream) 
+// pub fn retry(args: proc_macro::TokenStream, input: proc_macro::TokenStream)
 //     -> proc_macro::TokenStream {
-//     let args = parse_macro_input!(args as AttributeArgs);
+//     let args = parse_macro_input!(args with Punctuated::<Meta, Token![,]>::parse_terminated);
 //     let input = parse_macro_input!(input as ItemFn);
 //     retry_macro(args, input).into()
 // }
@@ -134,18 +268,18 @@ fn retry_macro(args: AttributeArgs, input: ItemFn) -> TokenStream {
 fn main() {
     // Example function to transform
     let input_code = r#"
-    #[retry(retries = 5, delay = 200)]
+    #[retry(retries = 5, backoff = "exponential", max_delay = 2000, jitter, deadline_ms = 10000, retry_if = is_transient)]
     async fn fetch_data(url: &str) -> Result<String, reqwest::Error> {
         let response = reqwest::get(url).await?;
         let text = response.text().await?;
         Ok(text)
     }
     "#;
-    
+
     // In a real proc macro, we'd parse the input_code and transform it
     // For demonstration, we'll just show what we're generating
     println!("A proc macro that would transform:\n{}", input_code);
-    
+
     // Mock the transformation process
     let mock_fn = syn::parse_str::<ItemFn>(r#"
     async fn fetch_data(url: &str) -> Result<String, reqwest::Error> {
@@ -154,9 +288,11 @@ fn main() {
         Ok(text)
     }
     "#).unwrap();
-    
-    let mock_args = syn::parse_str::<AttributeArgs>("retries = 5, delay = 200").unwrap_or_default();
-    
+
+    let mock_args = Punctuated::<Meta, Token![,]>::parse_terminated
+        .parse_str(r#"retries = 5, backoff = "exponential", max_delay = 2000, jitter, deadline_ms = 10000, retry_if = is_transient"#)
+        .unwrap_or_default();
+
     let transformed = retry_macro(mock_args, mock_fn);
     println!("\nInto:\n{}", transformed);
 }
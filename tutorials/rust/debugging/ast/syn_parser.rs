@@ -1,7 +1,115 @@
-use syn::{parse_file, File, ItemFn, Item, visit::Visit};
+// Requires proc-macro2's "span-locations" feature enabled in Cargo.toml:
+// without it, `Span::start()`/`end()` don't exist at all (E0599), so this
+// file won't compile - there's no silent (0, 0) fallback.
+use syn::{parse_file, File, ItemFn, Item, visit::Visit, spanned::Spanned};
+use proc_macro2::{Ident, Span};
+use annotate_snippets::{Level, Renderer, Snippet};
+use std::collections::HashMap;
 use std::fs::File as FsFile;
-use std::io::Read;
-use std::path::Path;
+use std::io::{BufRead, Read, Write};
+use std::path::{Path, PathBuf};
+
+const MAX_STATEMENTS: usize = 20;
+const MAX_ARGS: usize = 5;
+
+// Converts a (1-indexed line, 0-indexed column) pair, as reported by
+// `proc_macro2::LineColumn`, into a byte offset into `source`.
+fn line_col_to_offset(source: &str, line: usize, column: usize) -> usize {
+    let mut offset = 0;
+    for (i, text) in source.split('\n').enumerate() {
+        if i + 1 == line {
+            let byte_col = text.char_indices().nth(column).map(|(b, _)| b).unwrap_or(text.len());
+            return offset + byte_col;
+        }
+        offset += text.len() + 1; // +1 for the '\n' stripped by split
+    }
+    source.len()
+}
+
+// A single analyzer finding, rendered later as an annotated source snippet.
+struct Finding {
+    message: String,
+    severity: Level,
+    span: Span,
+}
+
+// Renders a batch of findings as annotated source snippets, rustc-style,
+// mapping each finding's span back into `content` by byte offset. Shared by
+// every analyzer in this file so they all report diagnostics the same way.
+fn report_findings(findings: &[Finding], content: &str, origin: &str) {
+    if findings.is_empty() {
+        return;
+    }
+
+    let renderer = Renderer::styled();
+    for finding in findings {
+        let start = finding.span.start();
+        let end = finding.span.end();
+        let start_offset = line_col_to_offset(content, start.line, start.column);
+        let end_offset = line_col_to_offset(content, end.line, end.column).max(start_offset + 1);
+
+        let message = finding.severity.title(&finding.message).snippet(
+            Snippet::source(content)
+                .line_start(1)
+                .origin(origin)
+                .fold(true)
+                .annotation(finding.severity.span(start_offset..end_offset).label(&finding.message)),
+        );
+
+        println!("{}", renderer.render(message));
+    }
+}
+
+// Attempts to evaluate an enum discriminant expression as an integer
+// constant. Handles the forms rustc itself accepts as a bare literal
+// discriminant (`= 3`, `= -1`); anything else (a const fn call, a named
+// const, ...) is left as "could not evaluate" rather than guessed at.
+fn eval_discriminant(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit_int), .. }) => {
+            lit_int.base10_parse::<i128>().ok()
+        }
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => {
+            eval_discriminant(expr).map(|v| -v)
+        }
+        _ => None,
+    }
+}
+
+// Reads an enum's `#[repr(..)]` attribute, if any, and returns the bit width
+// of the integer it selects, so discriminants can be wrapped the same way
+// rustc wraps them (e.g. `#[repr(u8)]` discriminants wrap at 256).
+fn repr_bit_width(attrs: &[syn::Attribute]) -> Option<u32> {
+    let mut bits = None;
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                bits = match ident.to_string().as_str() {
+                    "u8" | "i8" => Some(8),
+                    "u16" | "i16" => Some(16),
+                    "u32" | "i32" => Some(32),
+                    "u64" | "i64" | "usize" | "isize" => Some(64),
+                    _ => bits,
+                };
+            }
+            Ok(())
+        });
+    }
+    bits
+}
+
+fn wrap_to_bit_width(value: i128, bits: u32) -> i128 {
+    let modulus = 1i128 << bits;
+    let wrapped = value % modulus;
+    if wrapped < 0 {
+        wrapped + modulus
+    } else {
+        wrapped
+    }
+}
 
 // Visitor struct for analyzing Rust AST
 struct RustCodeVisitor {
@@ -11,6 +119,7 @@ struct RustCodeVisitor {
     trait_count: usize,
     impl_count: usize,
     macro_count: usize,
+    findings: Vec<Finding>,
 }
 
 impl RustCodeVisitor {
@@ -22,9 +131,10 @@ impl RustCodeVisitor {
             trait_count: 0,
             impl_count: 0,
             macro_count: 0,
+            findings: Vec::new(),
         }
     }
-    
+
     fn print_stats(&self) {
         println!("=== Rust Code Statistics ===");
         println!("Functions: {}", self.fn_count);
@@ -35,6 +145,57 @@ impl RustCodeVisitor {
         println!("Macros:    {}", self.macro_count);
         println!("===========================");
     }
+
+    // Walks one enum's variants in declaration order, re-deriving each
+    // variant's discriminant the way rustc does (implicit = previous + 1,
+    // explicit literal discriminants reset the running counter), and records
+    // a finding for every variant whose value an earlier variant already has,
+    // naming that first variant.
+    fn check_enum_discriminants(&mut self, node: &syn::ItemEnum) {
+        let bits = repr_bit_width(&node.attrs);
+        let mut seen: HashMap<i128, Ident> = HashMap::new();
+        let mut next_discriminant: i128 = 0;
+
+        for variant in &node.variants {
+            let value = match &variant.discriminant {
+                Some((_, expr)) => match eval_discriminant(expr) {
+                    Some(v) => v,
+                    None => {
+                        self.findings.push(Finding {
+                            message: format!(
+                                "enum '{}' variant '{}' has a discriminant that could not be evaluated as an integer literal; skipping its collision check",
+                                node.ident, variant.ident
+                            ),
+                            severity: Level::Note,
+                            span: variant.ident.span(),
+                        });
+                        next_discriminant += 1;
+                        continue;
+                    }
+                },
+                None => next_discriminant,
+            };
+
+            let value = match bits {
+                Some(bits) => wrap_to_bit_width(value, bits),
+                None => value,
+            };
+            next_discriminant = value + 1;
+
+            if let Some(original) = seen.get(&value) {
+                self.findings.push(Finding {
+                    message: format!(
+                        "enum '{}' variants '{}' and '{}' both have discriminant {}",
+                        node.ident, original, variant.ident, value
+                    ),
+                    severity: Level::Error,
+                    span: variant.ident.span(),
+                });
+            } else {
+                seen.insert(value, variant.ident.clone());
+            }
+        }
+    }
 }
 
 impl<'ast> Visit<'ast> for RustCodeVisitor {
@@ -43,27 +204,28 @@ impl<'ast> Visit<'ast> for RustCodeVisitor {
         // Continue visiting the function body
         syn::visit::visit_item_fn(self, node);
     }
-    
+
     fn visit_item_struct(&mut self, node: &'ast syn::ItemStruct) {
         self.struct_count += 1;
         syn::visit::visit_item_struct(self, node);
     }
-    
+
     fn visit_item_enum(&mut self, node: &'ast syn::ItemEnum) {
         self.enum_count += 1;
+        self.check_enum_discriminants(node);
         syn::visit::visit_item_enum(self, node);
     }
-    
+
     fn visit_item_trait(&mut self, node: &'ast syn::ItemTrait) {
         self.trait_count += 1;
         syn::visit::visit_item_trait(self, node);
     }
-    
+
     fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
         self.impl_count += 1;
         syn::visit::visit_item_impl(self, node);
     }
-    
+
     fn visit_macro(&mut self, node: &'ast syn::Macro) {
         self.macro_count += 1;
         syn::visit::visit_macro(self, node);
@@ -73,6 +235,7 @@ impl<'ast> Visit<'ast> for RustCodeVisitor {
 // Function analyzer that focuses on function details
 struct FunctionAnalyzer {
     functions: Vec<FunctionInfo>,
+    findings: Vec<Finding>,
 }
 
 struct FunctionInfo {
@@ -89,12 +252,13 @@ impl FunctionAnalyzer {
     fn new() -> Self {
         FunctionAnalyzer {
             functions: Vec::new(),
+            findings: Vec::new(),
         }
     }
-    
+
     fn analyze_function(&mut self, func: &ItemFn) {
         let name = func.sig.ident.to_string();
-        
+
         // Extract arguments
         let mut args = Vec::new();
         for input in &func.sig.inputs {
@@ -103,38 +267,63 @@ impl FunctionAnalyzer {
                     syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
                     _ => "_".to_string(),
                 };
-                
+
                 let arg_type = match &*pat_type.ty {
                     syn::Type::Path(type_path) => {
                         format!("{}", quote::quote!(#type_path))
                     },
                     _ => "unknown".to_string(),
                 };
-                
+
                 args.push((arg_name, arg_type));
             }
         }
-        
+
         // Extract return type
         let return_type = if let syn::ReturnType::Type(_, ty) = &func.sig.output {
             Some(format!("{}", quote::quote!(#ty)))
         } else {
             None
         };
-        
+
         // Function properties
         let is_async = func.sig.asyncness.is_some();
         let is_unsafe = func.sig.unsafety.is_some();
-        let is_public = if let Some(vis) = &func.vis {
-            matches!(vis, syn::Visibility::Public(_))
-        } else {
-            false
-        };
-        
-        // Estimate line count from span information
-        // This is approximate since we don't have line info without full parsing context
+        let is_public = matches!(func.vis, syn::Visibility::Public(_));
+
         let line_count = func.block.stmts.len();
-        
+
+        if line_count > MAX_STATEMENTS {
+            self.findings.push(Finding {
+                message: format!(
+                    "function '{}' has {} statements, exceeding the recommended maximum of {}",
+                    name, line_count, MAX_STATEMENTS
+                ),
+                severity: Level::Warning,
+                span: func.span(),
+            });
+        }
+
+        if args.len() > MAX_ARGS {
+            self.findings.push(Finding {
+                message: format!(
+                    "function '{}' takes {} arguments, exceeding the recommended maximum of {}",
+                    name, args.len(), MAX_ARGS
+                ),
+                severity: Level::Warning,
+                span: func.sig.ident.span(),
+            });
+        }
+
+        let has_doc_comment = func.attrs.iter().any(|attr| attr.path().is_ident("doc"));
+        if is_public && return_type.is_some() && !has_doc_comment {
+            self.findings.push(Finding {
+                message: format!("public function '{}' returns a value but has no doc comment", name),
+                severity: Level::Info,
+                span: func.sig.ident.span(),
+            });
+        }
+
         self.functions.push(FunctionInfo {
             name,
             args,
@@ -145,7 +334,11 @@ impl FunctionAnalyzer {
             line_count,
         });
     }
-    
+
+    fn report_findings(&self, content: &str, origin: &str) {
+        report_findings(&self.findings, content, origin);
+    }
+
     fn print_function_analysis(&self) {
         println!("=== Function Analysis ===");
         for func in &self.functions {
@@ -165,7 +358,7 @@ impl FunctionAnalyzer {
                 println!("  Return type: ()");
             }
             
-            println!("  Approximate size: {} statements", func.line_count);
+            println!("  Size: {} statements", func.line_count);
             println!();
         }
     }
@@ -184,7 +377,8 @@ fn analyze_rust_file(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let mut visitor = RustCodeVisitor::new();
     syn::visit::visit_file(&mut visitor, &syntax);
     visitor.print_stats();
-    
+    report_findings(&visitor.findings, &content, &path.display().to_string());
+
     // Analyze functions
     let mut fn_analyzer = FunctionAnalyzer::new();
     
@@ -196,14 +390,772 @@ fn analyze_rust_file(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     }
     
     fn_analyzer.print_function_analysis();
-    
+    fn_analyzer.report_findings(&content, &path.display().to_string());
+
+    Ok(())
+}
+
+// Just enough of `cargo metadata --format-version 1`'s JSON to find every
+// workspace package's source roots; we don't care about dependencies,
+// features, or anything else it reports.
+#[derive(serde::Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+}
+
+#[derive(serde::Deserialize)]
+struct CargoPackage {
+    name: String,
+    targets: Vec<CargoTarget>,
+}
+
+#[derive(serde::Deserialize)]
+struct CargoTarget {
+    src_path: String,
+}
+
+// Recursively collects every `.rs` file under `dir`, skipping `target/` so a
+// workspace's own build output doesn't get re-analyzed as source.
+fn collect_rs_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if path.file_name().map_or(false, |name| name == "target") {
+                continue;
+            }
+            collect_rs_files(&path, out);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            out.push(path);
+        }
+    }
+}
+
+// Per-crate rollup of the counts `RustCodeVisitor`/`FunctionAnalyzer` gather
+// per file, summed across every file reachable from that crate's targets.
+#[derive(Default)]
+struct CrateReport {
+    name: String,
+    file_count: usize,
+    fn_count: usize,
+    struct_count: usize,
+    enum_count: usize,
+    trait_count: usize,
+    impl_count: usize,
+    macro_count: usize,
+    functions_analyzed: usize,
+}
+
+// Shells out to `cargo metadata` to discover every package and target in the
+// current workspace, walks each package's source tree, and aggregates
+// `RustCodeVisitor`/`FunctionAnalyzer` findings into per-crate and
+// workspace-wide rollups. A file that fails to parse is reported and
+// skipped rather than aborting the whole run.
+fn run_workspace_analysis() -> Result<(), Box<dyn std::error::Error>> {
+    let output = std::process::Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "cargo metadata failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )
+        .into());
+    }
+
+    let metadata: CargoMetadata = serde_json::from_slice(&output.stdout)?;
+
+    let mut reports = Vec::new();
+
+    for package in &metadata.packages {
+        let mut src_roots: Vec<PathBuf> = Vec::new();
+        for target in &package.targets {
+            if let Some(root) = Path::new(&target.src_path).parent() {
+                if !src_roots.iter().any(|r| r == root) {
+                    src_roots.push(root.to_path_buf());
+                }
+            }
+        }
+
+        let mut files = Vec::new();
+        for root in &src_roots {
+            collect_rs_files(root, &mut files);
+        }
+        files.sort();
+        files.dedup();
+
+        let mut report = CrateReport {
+            name: package.name.clone(),
+            ..Default::default()
+        };
+
+        for file in &files {
+            let content = match std::fs::read_to_string(file) {
+                Ok(content) => content,
+                Err(e) => {
+                    eprintln!("skipping {} (read error): {}", file.display(), e);
+                    continue;
+                }
+            };
+
+            let syntax = match parse_file(&content) {
+                Ok(syntax) => syntax,
+                Err(e) => {
+                    eprintln!("skipping {} (parse error): {}", file.display(), e);
+                    continue;
+                }
+            };
+
+            let mut visitor = RustCodeVisitor::new();
+            syn::visit::visit_file(&mut visitor, &syntax);
+            report_findings(&visitor.findings, &content, &file.display().to_string());
+
+            let mut fn_analyzer = FunctionAnalyzer::new();
+            for item in &syntax.items {
+                if let Item::Fn(func) = item {
+                    fn_analyzer.analyze_function(func);
+                }
+            }
+            fn_analyzer.report_findings(&content, &file.display().to_string());
+
+            report.file_count += 1;
+            report.fn_count += visitor.fn_count;
+            report.struct_count += visitor.struct_count;
+            report.enum_count += visitor.enum_count;
+            report.trait_count += visitor.trait_count;
+            report.impl_count += visitor.impl_count;
+            report.macro_count += visitor.macro_count;
+            report.functions_analyzed += fn_analyzer.functions.len();
+        }
+
+        reports.push(report);
+    }
+
+    println!("=== Per-Crate Rollups ===");
+    let mut totals = CrateReport::default();
+    for report in &reports {
+        println!(
+            "{}: {} files, {} fns, {} structs, {} enums, {} traits, {} impls, {} macros",
+            report.name,
+            report.file_count,
+            report.fn_count,
+            report.struct_count,
+            report.enum_count,
+            report.trait_count,
+            report.impl_count,
+            report.macro_count
+        );
+        totals.file_count += report.file_count;
+        totals.fn_count += report.fn_count;
+        totals.struct_count += report.struct_count;
+        totals.enum_count += report.enum_count;
+        totals.trait_count += report.trait_count;
+        totals.impl_count += report.impl_count;
+        totals.macro_count += report.macro_count;
+        totals.functions_analyzed += report.functions_analyzed;
+    }
+
+    println!("=== Workspace Totals ===");
+    println!(
+        "{} crates, {} files, {} fns, {} structs, {} enums, {} traits, {} impls, {} macros ({} functions analyzed in detail)",
+        reports.len(),
+        totals.file_count,
+        totals.fn_count,
+        totals.struct_count,
+        totals.enum_count,
+        totals.trait_count,
+        totals.impl_count,
+        totals.macro_count,
+        totals.functions_analyzed
+    );
+
+    Ok(())
+}
+
+// --- LSP mode ---------------------------------------------------------
+//
+// A minimal JSON-RPC-over-stdio language server. Each `didOpen`/`didChange`
+// re-parses the whole buffer and re-runs `RustCodeVisitor`/`FunctionAnalyzer`,
+// then republishes their findings as `textDocument/publishDiagnostics`.
+// `textDocument/documentSymbol` reuses the same top-level item walk to report
+// every function/struct/enum/trait/impl with its range.
+
+// Converts a `proc_macro2::Span`'s 1-indexed line / 0-indexed column into an
+// LSP `Range` (0-indexed lines, same column convention as the rest of this file).
+fn span_to_range(span: Span) -> serde_json::Value {
+    let start = span.start();
+    let end = span.end();
+    serde_json::json!({
+        "start": { "line": start.line.saturating_sub(1), "character": start.column },
+        "end": { "line": end.line.saturating_sub(1), "character": end.column },
+    })
+}
+
+fn finding_to_diagnostic(finding: &Finding) -> serde_json::Value {
+    let severity = match finding.severity {
+        Level::Error => 1,
+        Level::Warning => 2,
+        Level::Info => 3,
+        _ => 4,
+    };
+
+    serde_json::json!({
+        "range": span_to_range(finding.span),
+        "severity": severity,
+        "source": "syn_parser",
+        "message": finding.message,
+    })
+}
+
+// Reports every top-level function/struct/enum/trait/impl as a
+// `DocumentSymbol`-shaped JSON value, re-parsing `content` and taking each
+// item's range from its span.
+fn document_symbols(content: &str) -> Vec<serde_json::Value> {
+    let Ok(syntax) = parse_file(content) else {
+        return Vec::new();
+    };
+
+    syntax
+        .items
+        .iter()
+        .filter_map(|item| {
+            let (name, kind) = match item {
+                Item::Fn(f) => (f.sig.ident.to_string(), 12), // SymbolKind::Function
+                Item::Struct(s) => (s.ident.to_string(), 23), // SymbolKind::Struct
+                Item::Enum(e) => (e.ident.to_string(), 10),   // SymbolKind::Enum
+                Item::Trait(t) => (t.ident.to_string(), 11),  // SymbolKind::Interface
+                Item::Impl(i) => {
+                    let ty = &i.self_ty;
+                    (format!("impl {}", quote::quote!(#ty)), 12) // SymbolKind::Function (closest fit)
+                }
+                _ => return None,
+            };
+
+            let range = span_to_range(item.span());
+            Some(serde_json::json!({
+                "name": name,
+                "kind": kind,
+                "range": range,
+                "selectionRange": range,
+            }))
+        })
+        .collect()
+}
+
+// Reads one `Content-Length`-framed JSON-RPC message off `reader`, or `None`
+// at EOF.
+fn read_message<R: BufRead>(
+    reader: &mut R,
+) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let content_length = match content_length {
+        Some(len) => len,
+        None => return Ok(None),
+    };
+
+    let mut buf = vec![0u8; content_length];
+    reader.read_exact(&mut buf)?;
+    Ok(Some(serde_json::from_slice(&buf)?))
+}
+
+fn write_message(value: &serde_json::Value) -> std::io::Result<()> {
+    let body = serde_json::to_string(value).expect("JSON-RPC payload is always serializable");
+    let mut stdout = std::io::stdout();
+    write!(stdout, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    stdout.flush()
+}
+
+// The document store backing the LSP session: every open buffer, keyed by
+// URI, re-analyzed from scratch on every edit.
+struct LspServer {
+    documents: HashMap<String, String>,
+}
+
+impl LspServer {
+    fn new() -> Self {
+        LspServer { documents: HashMap::new() }
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> std::io::Result<()> {
+        let diagnostics = match self.documents.get(uri).and_then(|content| parse_file(content).ok()) {
+            Some(syntax) => {
+                let mut visitor = RustCodeVisitor::new();
+                syn::visit::visit_file(&mut visitor, &syntax);
+
+                let mut fn_analyzer = FunctionAnalyzer::new();
+                for item in &syntax.items {
+                    if let Item::Fn(func) = item {
+                        fn_analyzer.analyze_function(func);
+                    }
+                }
+
+                visitor
+                    .findings
+                    .iter()
+                    .chain(fn_analyzer.findings.iter())
+                    .map(finding_to_diagnostic)
+                    .collect::<Vec<_>>()
+            }
+            None => Vec::new(),
+        };
+
+        write_message(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        }))
+    }
+}
+
+// Runs the analyzer as an LSP server over stdio until the client sends
+// `exit` or closes stdin.
+fn run_lsp_server() -> Result<(), Box<dyn std::error::Error>> {
+    let stdin = std::io::stdin();
+    let mut reader = std::io::BufReader::new(stdin.lock());
+    let mut server = LspServer::new();
+
+    while let Some(message) = read_message(&mut reader)? {
+        let method = message.get("method").and_then(|m| m.as_str()).unwrap_or("");
+        let params = message.get("params").cloned().unwrap_or(serde_json::Value::Null);
+        let id = message.get("id").cloned();
+
+        match method {
+            "initialize" => {
+                write_message(&serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "result": {
+                        "capabilities": {
+                            "textDocumentSync": 1,
+                            "documentSymbolProvider": true,
+                        }
+                    }
+                }))?;
+            }
+            "textDocument/didOpen" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("").to_string();
+                let text = params["textDocument"]["text"].as_str().unwrap_or("").to_string();
+                server.documents.insert(uri.clone(), text);
+                server.publish_diagnostics(&uri)?;
+            }
+            "textDocument/didChange" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("").to_string();
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    server.documents.insert(uri.clone(), text.to_string());
+                }
+                server.publish_diagnostics(&uri)?;
+            }
+            "textDocument/documentSymbol" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or("");
+                let symbols = server
+                    .documents
+                    .get(uri)
+                    .map(|content| document_symbols(content))
+                    .unwrap_or_default();
+                write_message(&serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": symbols }))?;
+            }
+            "shutdown" => {
+                write_message(&serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": serde_json::Value::Null }))?;
+            }
+            "exit" => break,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+// --- UI test harness ------------------------------------------------------
+//
+// A compiletest-style harness: fixtures carry `//~ LEVEL substring`
+// annotations describing the diagnostics the analyzer is expected to emit
+// for that file, with `//~^`/`//~^^` (one caret per line) pointing the
+// annotation at a line above the comment instead of its own line. Running
+// the harness re-derives each annotation's target line, runs the analyzer
+// over the fixture, and matches emitted diagnostics against expectations by
+// (line, level, message substring).
+//
+// `--test-ui` with no explicit path scans `tests/ui`, so every fixture in
+// there is expected to PASS. Fixtures that are deliberately mismatched (to
+// prove the harness actually catches a wrong annotation) live in
+// `tests/ui-negative` instead and are run explicitly, e.g.
+// `--test-ui tests/ui-negative`, so they don't fail the default self-test.
+
+fn level_tag(level: Level) -> &'static str {
+    match level {
+        Level::Error => "ERROR",
+        Level::Warning => "WARN",
+        Level::Info => "INFO",
+        _ => "NOTE",
+    }
+}
+
+struct ExpectedAnnotation {
+    line: usize,
+    level: &'static str,
+    substring: String,
+}
+
+// Scans a fixture's source for `//~` annotations and resolves each one to
+// the absolute line number it targets.
+fn parse_annotations(content: &str) -> Vec<ExpectedAnnotation> {
+    let mut annotations = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let Some(pos) = line.find("//~") else {
+            continue;
+        };
+        let rest = &line[pos + 3..];
+
+        let carets = rest.chars().take_while(|&c| c == '^').count();
+        let target_line = line_no.saturating_sub(carets);
+        let rest = rest.trim_start_matches('^').trim_start();
+
+        let Some((level_str, substring)) = rest.split_once(char::is_whitespace) else {
+            continue;
+        };
+
+        let level = match level_str {
+            "ERROR" => "ERROR",
+            "WARN" => "WARN",
+            "INFO" => "INFO",
+            "NOTE" => "NOTE",
+            _ => continue,
+        };
+
+        annotations.push(ExpectedAnnotation {
+            line: target_line,
+            level,
+            substring: substring.trim().to_string(),
+        });
+    }
+
+    annotations
+}
+
+// Walks `dir` for `.rs` fixtures, runs the analyzer over each, and matches
+// emitted diagnostics against the fixture's `//~` annotations. Prints a
+// PASS/FAIL line per fixture and returns whether every fixture matched.
+fn run_ui_tests(dir: &Path) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut fixtures = Vec::new();
+    collect_rs_files(dir, &mut fixtures);
+    fixtures.sort();
+
+    let mut all_passed = true;
+
+    for fixture in &fixtures {
+        let content = std::fs::read_to_string(fixture)?;
+        let expected = parse_annotations(&content);
+
+        let syntax = match parse_file(&content) {
+            Ok(syntax) => syntax,
+            Err(e) => {
+                println!("FAIL {} (parse error: {})", fixture.display(), e);
+                all_passed = false;
+                continue;
+            }
+        };
+
+        let mut visitor = RustCodeVisitor::new();
+        syn::visit::visit_file(&mut visitor, &syntax);
+
+        let mut fn_analyzer = FunctionAnalyzer::new();
+        for item in &syntax.items {
+            if let Item::Fn(func) = item {
+                fn_analyzer.analyze_function(func);
+            }
+        }
+
+        let actual: Vec<(usize, &'static str, String)> = visitor
+            .findings
+            .iter()
+            .chain(fn_analyzer.findings.iter())
+            .map(|finding| (finding.span.start().line, level_tag(finding.severity), finding.message.clone()))
+            .collect();
+
+        let mut matched_actual = vec![false; actual.len()];
+        let mut unmatched_expected = Vec::new();
+
+        for expectation in &expected {
+            let found = actual.iter().enumerate().position(|(i, (line, level, message))| {
+                !matched_actual[i]
+                    && *line == expectation.line
+                    && *level == expectation.level
+                    && message.contains(&expectation.substring)
+            });
+
+            match found {
+                Some(i) => matched_actual[i] = true,
+                None => unmatched_expected.push(expectation),
+            }
+        }
+
+        let unexpected: Vec<_> = actual
+            .iter()
+            .zip(matched_actual.iter())
+            .filter(|(_, matched)| !**matched)
+            .map(|(finding, _)| finding)
+            .collect();
+
+        if unmatched_expected.is_empty() && unexpected.is_empty() {
+            println!("PASS {}", fixture.display());
+        } else {
+            all_passed = false;
+            println!("FAIL {}", fixture.display());
+            for expectation in &unmatched_expected {
+                println!(
+                    "  expected {} '{}' at line {} was not emitted",
+                    expectation.level, expectation.substring, expectation.line
+                );
+            }
+            for (line, level, message) in &unexpected {
+                println!("  unexpected {} at line {}: {}", level, line, message);
+            }
+        }
+    }
+
+    Ok(all_passed)
+}
+
+// --- item-tree pretty-printer ----------------------------------------------
+//
+// Renders a deterministic, indentation-structured text tree of the items
+// reachable from a file: functions with their signature, structs with their
+// fields, enums with their variants and discriminants, traits with their
+// associated items, and impls with their target type and trait, descending
+// into `mod` blocks rather than stopping at the top level. Siblings are
+// sorted by a stable (kind, name) key so the output is diffable across
+// unrelated edits — useful for snapshot-testing a crate's API surface.
+
+fn format_type(ty: &syn::Type) -> String {
+    quote::quote!(#ty).to_string()
+}
+
+fn format_fn_signature(sig: &syn::Signature) -> String {
+    let params = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Receiver(receiver) => match (&receiver.reference, &receiver.mutability) {
+                (Some(_), Some(_)) => "&mut self".to_string(),
+                (Some(_), None) => "&self".to_string(),
+                (None, _) => "self".to_string(),
+            },
+            syn::FnArg::Typed(pat_type) => {
+                let name = match &*pat_type.pat {
+                    syn::Pat::Ident(pat_ident) => pat_ident.ident.to_string(),
+                    _ => "_".to_string(),
+                };
+                format!("{}: {}", name, format_type(&pat_type.ty))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let ret = match &sig.output {
+        syn::ReturnType::Type(_, ty) => format!(" -> {}", format_type(ty)),
+        syn::ReturnType::Default => String::new(),
+    };
+
+    let mut flags = Vec::new();
+    if sig.asyncness.is_some() {
+        flags.push("async");
+    }
+    if sig.unsafety.is_some() {
+        flags.push("unsafe");
+    }
+    let flag_prefix = if flags.is_empty() { String::new() } else { format!("{} ", flags.join(" ")) };
+
+    format!("{}fn {}({}){}", flag_prefix, sig.ident, params, ret)
+}
+
+fn vis_prefix(vis: &syn::Visibility) -> &'static str {
+    match vis {
+        syn::Visibility::Public(_) => "pub ",
+        _ => "",
+    }
+}
+
+// A stable `(kind, name)` key used to order siblings so the tree stays
+// diffable regardless of declaration order in the source file. Items with no
+// stable identity (e.g. `use`, `const`) get key 6 and are left out of the tree.
+fn item_sort_key(item: &Item) -> (u8, String) {
+    match item {
+        Item::Fn(f) => (0, f.sig.ident.to_string()),
+        Item::Struct(s) => (1, s.ident.to_string()),
+        Item::Enum(e) => (2, e.ident.to_string()),
+        Item::Trait(t) => (3, t.ident.to_string()),
+        Item::Impl(i) => (4, format_type(&i.self_ty)),
+        Item::Mod(m) => (5, m.ident.to_string()),
+        _ => (6, String::new()),
+    }
+}
+
+fn print_item_tree(item: &Item, depth: usize) {
+    let indent = "  ".repeat(depth);
+    match item {
+        Item::Fn(f) => {
+            println!("{}{}{}", indent, vis_prefix(&f.vis), format_fn_signature(&f.sig));
+        }
+        Item::Struct(s) => {
+            println!("{}{}struct {}", indent, vis_prefix(&s.vis), s.ident);
+            let mut fields: Vec<(String, String)> = match &s.fields {
+                syn::Fields::Named(named) => named
+                    .named
+                    .iter()
+                    .map(|field| (field.ident.as_ref().map(ToString::to_string).unwrap_or_default(), format_type(&field.ty)))
+                    .collect(),
+                syn::Fields::Unnamed(unnamed) => unnamed
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| (i.to_string(), format_type(&field.ty)))
+                    .collect(),
+                syn::Fields::Unit => Vec::new(),
+            };
+            fields.sort();
+            for (name, ty) in &fields {
+                println!("{}  {}: {}", indent, name, ty);
+            }
+        }
+        Item::Enum(e) => {
+            println!("{}{}enum {}", indent, vis_prefix(&e.vis), e.ident);
+            let bits = repr_bit_width(&e.attrs);
+            let mut next_discriminant: i128 = 0;
+            let mut variants = Vec::new();
+            for variant in &e.variants {
+                let value = match &variant.discriminant {
+                    Some((_, expr)) => eval_discriminant(expr).unwrap_or(next_discriminant),
+                    None => next_discriminant,
+                };
+                let value = match bits {
+                    Some(bits) => wrap_to_bit_width(value, bits),
+                    None => value,
+                };
+                next_discriminant = value + 1;
+                variants.push((variant.ident.to_string(), value));
+            }
+            variants.sort();
+            for (name, value) in &variants {
+                println!("{}  {} = {}", indent, name, value);
+            }
+        }
+        Item::Trait(t) => {
+            println!("{}{}trait {}", indent, vis_prefix(&t.vis), t.ident);
+            let mut assoc: Vec<String> = t
+                .items
+                .iter()
+                .filter_map(|trait_item| match trait_item {
+                    syn::TraitItem::Fn(f) => Some(format_fn_signature(&f.sig)),
+                    syn::TraitItem::Type(ty) => Some(format!("type {}", ty.ident)),
+                    syn::TraitItem::Const(c) => Some(format!("const {}: {}", c.ident, format_type(&c.ty))),
+                    _ => None,
+                })
+                .collect();
+            assoc.sort();
+            for item in &assoc {
+                println!("{}  {}", indent, item);
+            }
+        }
+        Item::Impl(i) => {
+            let target = format_type(&i.self_ty);
+            let trait_prefix = i
+                .trait_
+                .as_ref()
+                .map(|(_, path, _)| format!("{} for ", quote::quote!(#path)))
+                .unwrap_or_default();
+            println!("{}impl {}{}", indent, trait_prefix, target);
+        }
+        Item::Mod(m) => {
+            println!("{}{}mod {}", indent, vis_prefix(&m.vis), m.ident);
+            if let Some((_, items)) = &m.content {
+                print_item_tree_sorted(items, depth + 1);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn print_item_tree_sorted(items: &[Item], depth: usize) {
+    let mut sorted: Vec<&Item> = items.iter().filter(|item| item_sort_key(item).0 != 6).collect();
+    sorted.sort_by_key(|item| item_sort_key(item));
+    for item in sorted {
+        print_item_tree(item, depth);
+    }
+}
+
+fn run_item_tree(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(path)?;
+    let syntax = parse_file(&content)?;
+    print_item_tree_sorted(&syntax.items, 0);
     Ok(())
 }
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.get(1).map(String::as_str) == Some("--item-tree") {
+        let path = args.get(2).map(Path::new).unwrap_or_else(|| Path::new("ast/syn_parser.rs"));
+        if let Err(e) = run_item_tree(path) {
+            eprintln!("Error printing item tree: {}", e);
+        }
+        return;
+    }
+
+    if args.get(1).map(String::as_str) == Some("--test-ui") {
+        let dir = args.get(2).map(Path::new).unwrap_or_else(|| Path::new("tests/ui"));
+        match run_ui_tests(dir) {
+            Ok(true) => println!("all UI tests passed"),
+            Ok(false) => {
+                eprintln!("UI test failures");
+                std::process::exit(1);
+            }
+            Err(e) => eprintln!("Error running UI tests: {}", e),
+        }
+        return;
+    }
+
+    if args.get(1).map(String::as_str) == Some("--lsp") {
+        if let Err(e) = run_lsp_server() {
+            eprintln!("LSP server error: {}", e);
+        }
+        return;
+    }
+
+    if args.get(1).map(String::as_str) == Some("--workspace") {
+        if let Err(e) = run_workspace_analysis() {
+            eprintln!("Error running workspace analysis: {}", e);
+        }
+        return;
+    }
+
     // Example usage with this file
     let path = Path::new("ast/syn_parser.rs");
-    
+
     match analyze_rust_file(path) {
         Ok(_) => println!("Analysis complete"),
         Err(e) => eprintln!("Error analyzing file: {}", e),
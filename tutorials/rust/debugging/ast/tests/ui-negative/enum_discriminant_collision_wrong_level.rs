@@ -0,0 +1,14 @@
+// Deliberately mismatched fixture: the analyzer reports this collision as
+// ERROR, but the annotation below claims WARN, so `--test-ui` should FAIL
+// on this fixture. Kept around to prove the harness actually checks level
+// and not just the message substring.
+//
+// Lives in `tests/ui-negative/` rather than `tests/ui/` (the default scan
+// directory for a bare `--test-ui`) so this permanent, intentional failure
+// doesn't fail the normal self-test run. Exercise it explicitly instead:
+// `cargo run -- --test-ui tests/ui-negative`.
+enum Status {
+    Active = 0,
+    Inactive = 1,
+    Suspended = 0, //~ WARN both have discriminant
+}
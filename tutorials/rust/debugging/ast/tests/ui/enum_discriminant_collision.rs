@@ -0,0 +1,7 @@
+// Passing fixture for `--test-ui`: two variants explicitly share the
+// discriminant `1`, which the analyzer is expected to flag.
+enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 1, //~ ERROR both have discriminant
+}
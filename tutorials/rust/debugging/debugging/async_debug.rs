@@ -1,45 +1,269 @@
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use std::fmt;
+use std::cell::RefCell;
 
-// A simple logger for tracing async execution
+// Cached rendering of "HH:MM:SS" keyed by unix-second, mirroring the cached
+// `LastRenderedNow` trick used by high-throughput HTTP date headers: the
+// whole-second prefix only needs re-rendering once a second actually ticks
+// over, and the sub-second part is always computed fresh.
+thread_local! {
+    static CACHED_TIMESTAMP: RefCell<Option<(i64, String)>> = RefCell::new(None);
+}
+
+// Default, cached rendering path. Enable the `uncached_timestamps` Cargo
+// feature (`cfg(feature = "uncached_timestamps")`) to fall back to the naive
+// path below for correctness testing.
+#[cfg(not(feature = "uncached_timestamps"))]
+fn render_timestamp() -> String {
+    let now = chrono::Local::now();
+    let unix_secs = now.timestamp();
+    let millis = now.timestamp_subsec_millis();
+
+    let prefix = CACHED_TIMESTAMP.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if let Some((cached_secs, rendered)) = cache.as_ref() {
+            if *cached_secs == unix_secs {
+                return rendered.clone();
+            }
+        }
+        let rendered = now.format("%H:%M:%S").to_string();
+        *cache = Some((unix_secs, rendered.clone()));
+        rendered
+    });
+
+    format!("{}.{:03}", prefix, millis)
+}
+
+// Uncached fallback: reformats the full timestamp on every call.
+#[cfg(feature = "uncached_timestamps")]
+fn render_timestamp() -> String {
+    chrono::Local::now().format("%H:%M:%S%.3f").to_string()
+}
+
+// Severity of a log record, in increasing order of importance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+// A structured log record, kept around so it can be replayed through any sink.
+struct Record {
+    timestamp: String,
+    level: Level,
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+// A destination for log records. Implementations decide how to render them.
+trait Sink: Send + Sync {
+    fn emit(&self, record: &Record);
+}
+
+// Human-readable sink, e.g. `12:00:00.000 INFO Starting fetch_data id=42`
+struct ConsoleSink;
+
+impl Sink for ConsoleSink {
+    fn emit(&self, record: &Record) {
+        print!("{} {:<5} {}", record.timestamp, record.level, record.message);
+        for (key, value) in &record.fields {
+            print!(" {}={}", key, value);
+        }
+        println!();
+    }
+}
+
+// Machine-parseable sink, one JSON object per line.
+struct JsonLinesSink;
+
+impl Sink for JsonLinesSink {
+    fn emit(&self, record: &Record) {
+        let mut fields = String::new();
+        for (key, value) in &record.fields {
+            if !fields.is_empty() {
+                fields.push(',');
+            }
+            fields.push_str(&format!("\"{}\":\"{}\"", escape_json(key), escape_json(value)));
+        }
+        println!(
+            "{{\"ts\":\"{}\",\"level\":\"{}\",\"msg\":\"{}\",\"fields\":{{{}}}}}",
+            record.timestamp,
+            record.level,
+            escape_json(&record.message),
+            fields
+        );
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Per-future poll statistics, updated by every `DebugFuture` on each poll so
+// `dump_log` can summarize the slowest polls and the most-starved futures.
+#[derive(Default, Clone, Copy)]
+struct FuturePollStats {
+    total_polls: u64,
+    slowest_poll: Duration,
+    max_consecutive_pending: u32,
+}
+
+// A structured, leveled logger with a pluggable sink.
 struct AsyncLogger {
-    log: Arc<Mutex<Vec<String>>>,
+    records: Arc<Mutex<Vec<Record>>>,
+    sink: Arc<dyn Sink>,
+    poll_stats: Arc<Mutex<std::collections::HashMap<&'static str, FuturePollStats>>>,
 }
 
 impl AsyncLogger {
     fn new() -> Self {
+        Self::with_sink(Arc::new(ConsoleSink))
+    }
+
+    fn with_sink(sink: Arc<dyn Sink>) -> Self {
         AsyncLogger {
-            log: Arc::new(Mutex::new(Vec::new())),
+            records: Arc::new(Mutex::new(Vec::new())),
+            sink,
+            poll_stats: Arc::new(Mutex::new(std::collections::HashMap::new())),
         }
     }
-    
-    fn log(&self, message: &str) {
-        let mut log = self.log.lock().unwrap();
-        log.push(format!("{}: {}", chrono::Local::now().format("%H:%M:%S.%3f"), message));
+
+    fn json() -> Self {
+        Self::with_sink(Arc::new(JsonLinesSink))
+    }
+
+    fn log(&self, level: Level, message: &str, fields: &[(&str, &dyn fmt::Display)]) {
+        let record = Record {
+            timestamp: render_timestamp(),
+            level,
+            message: message.to_string(),
+            fields: fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        };
+        self.sink.emit(&record);
+        self.records.lock().unwrap().push(record);
+    }
+
+    // Record how long a single `inner.poll` call took for `name`.
+    fn record_poll_duration(&self, name: &'static str, duration: Duration) {
+        let mut stats = self.poll_stats.lock().unwrap();
+        let entry = stats.entry(name).or_default();
+        entry.total_polls += 1;
+        if duration > entry.slowest_poll {
+            entry.slowest_poll = duration;
+        }
+    }
+
+    // Record the longest run of consecutive `Pending` results seen for `name`.
+    fn record_pending_streak(&self, name: &'static str, consecutive_pending: u32) {
+        let mut stats = self.poll_stats.lock().unwrap();
+        let entry = stats.entry(name).or_default();
+        if consecutive_pending > entry.max_consecutive_pending {
+            entry.max_consecutive_pending = consecutive_pending;
+        }
     }
-    
+
     fn dump_log(&self) {
-        let log = self.log.lock().unwrap();
+        let records = self.records.lock().unwrap();
         println!("=== Async Execution Log ===");
-        for entry in log.iter() {
-            println!("{}", entry);
+        for record in records.iter() {
+            print!("{} {:<5} {}", record.timestamp, record.level, record.message);
+            for (key, value) in &record.fields {
+                print!(" {}={}", key, value);
+            }
+            println!();
         }
         println!("===========================");
+        drop(records);
+
+        let stats = self.poll_stats.lock().unwrap();
+        if !stats.is_empty() {
+            let mut by_slowest: Vec<_> = stats.iter().collect();
+            by_slowest.sort_by(|a, b| b.1.slowest_poll.cmp(&a.1.slowest_poll));
+
+            let mut by_starved: Vec<_> = stats.iter().collect();
+            by_starved.sort_by(|a, b| b.1.max_consecutive_pending.cmp(&a.1.max_consecutive_pending));
+
+            println!("=== Poll Budget Summary ===");
+            println!("Slowest polls:");
+            for (name, s) in &by_slowest {
+                println!("  {}: {:?} over {} polls", name, s.slowest_poll, s.total_polls);
+            }
+            println!("Most-starved futures:");
+            for (name, s) in &by_starved {
+                println!("  {}: {} consecutive pending polls", name, s.max_consecutive_pending);
+            }
+            println!("===========================");
+        }
     }
-    
+
     fn clone(&self) -> Self {
         AsyncLogger {
-            log: Arc::clone(&self.log),
+            records: Arc::clone(&self.records),
+            sink: Arc::clone(&self.sink),
+            poll_stats: Arc::clone(&self.poll_stats),
         }
     }
 }
 
+// A single poll running longer than this is the signature of the
+// `buggy_task` pattern: a blocking operation or a lock held across an await
+// stalling the executor.
+const SLOW_POLL_THRESHOLD: Duration = Duration::from_millis(50);
+
+// Cooperative poll budget modeled on tokio's `coop`: a task that keeps
+// returning `Pending` without actually yielding to the executor can starve
+// its neighbors, so every `COOP_BUDGET` polls we force a yield.
+const COOP_BUDGET: u32 = 128;
+
+// Relays wakes to the real waker while also timestamping them, so a
+// `DebugFuture` can tell "woken, then not re-polled promptly" (genuine
+// executor starvation) apart from "still legitimately `Pending`, just
+// hasn't been woken yet" (e.g. a timer that hasn't fired).
+struct PollWaker {
+    inner: std::task::Waker,
+    woken_at: Arc<Mutex<Option<std::time::Instant>>>,
+}
+
+impl std::task::Wake for PollWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        *self.woken_at.lock().unwrap() = Some(std::time::Instant::now());
+        self.inner.wake_by_ref();
+    }
+}
+
 // Custom future wrapper for debugging
 struct DebugFuture<F> {
     inner: F,
     name: &'static str,
     logger: AsyncLogger,
+    poll_count: u64,
+    budget: u32,
+    woken_at: Arc<Mutex<Option<std::time::Instant>>>,
+    consecutive_pending: u32,
 }
 
 impl<F> DebugFuture<F> {
@@ -48,27 +272,96 @@ impl<F> DebugFuture<F> {
             inner: future,
             name,
             logger,
+            poll_count: 0,
+            budget: COOP_BUDGET,
+            woken_at: Arc::new(Mutex::new(None)),
+            consecutive_pending: 0,
         }
     }
 }
 
 impl<F: std::future::Future> std::future::Future for DebugFuture<F> {
     type Output = F::Output;
-    
+
     fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
         // Safety: we're not moving any fields out of self
         let this = unsafe { self.get_unchecked_mut() };
-        this.logger.log(&format!("Polling future '{}'", this.name));
-        
+        this.poll_count += 1;
+
+        // Futures that are woken but not promptly re-polled are starved by
+        // the executor. A long gap between polls is not on its own evidence
+        // of that - a future waiting on e.g. a multi-second timer is
+        // correctly `Pending` the whole time and simply hasn't been woken
+        // yet. So we only compare against the timestamp `PollWaker` records
+        // when *this* future's waker actually fires, not the previous poll.
+        let now = std::time::Instant::now();
+        if let Some(woken_at) = this.woken_at.lock().unwrap().take() {
+            let gap = now.duration_since(woken_at);
+            if gap > SLOW_POLL_THRESHOLD {
+                this.logger.log(
+                    Level::Warn,
+                    "Future starved: woken but not polled promptly",
+                    &[("name", &this.name), ("gap_ms", &gap.as_millis())],
+                );
+            }
+        }
+
+        if this.budget == 0 {
+            this.budget = COOP_BUDGET;
+            this.logger.log(
+                Level::Debug,
+                "Cooperative poll budget exhausted, yielding",
+                &[("name", &this.name)],
+            );
+            cx.waker().wake_by_ref();
+            return std::task::Poll::Pending;
+        }
+        this.budget -= 1;
+
+        this.logger.log(
+            Level::Debug,
+            "Polling future",
+            &[("name", &this.name), ("poll_count", &this.poll_count)],
+        );
+
         // Safety: we're not moving the inner future out of self
         let inner = unsafe { std::pin::Pin::new_unchecked(&mut this.inner) };
-        match inner.poll(cx) {
+        let poll_waker = std::task::Waker::from(Arc::new(PollWaker {
+            inner: cx.waker().clone(),
+            woken_at: Arc::clone(&this.woken_at),
+        }));
+        let mut inner_cx = std::task::Context::from_waker(&poll_waker);
+        let poll_start = std::time::Instant::now();
+        let poll_result = inner.poll(&mut inner_cx);
+        let poll_duration = poll_start.elapsed();
+
+        this.logger.record_poll_duration(this.name, poll_duration);
+        if poll_duration > SLOW_POLL_THRESHOLD {
+            this.logger.log(
+                Level::Warn,
+                "Slow poll detected, possible blocking-in-async",
+                &[("name", &this.name), ("duration_ms", &poll_duration.as_millis())],
+            );
+        }
+
+        match poll_result {
             std::task::Poll::Ready(result) => {
-                this.logger.log(&format!("Future '{}' completed", this.name));
+                this.consecutive_pending = 0;
+                this.logger.log(
+                    Level::Info,
+                    "Future completed",
+                    &[("name", &this.name), ("poll_count", &this.poll_count)],
+                );
                 std::task::Poll::Ready(result)
             },
             std::task::Poll::Pending => {
-                this.logger.log(&format!("Future '{}' pending", this.name));
+                this.consecutive_pending += 1;
+                this.logger.record_pending_streak(this.name, this.consecutive_pending);
+                this.logger.log(
+                    Level::Trace,
+                    "Future pending",
+                    &[("name", &this.name), ("poll_count", &this.poll_count)],
+                );
                 std::task::Poll::Pending
             }
         }
@@ -80,146 +373,311 @@ fn debug_future<F: std::future::Future>(future: F, name: &'static str, logger: A
     DebugFuture::new(future, name, logger)
 }
 
+// A small smol-style single-threaded executor: a per-thread run queue plus a
+// waker-driven reactor, with a configurable poll budget per time quantum.
+// Unlike tokio's work-stealing scheduler this drives tasks directly, so it
+// can observe (and throttle) exactly how often each one is polled.
+mod throttling_executor {
+    use super::{AsyncLogger, Level};
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::time::{Duration, Instant};
+
+    struct Task {
+        future: RefCell<Pin<Box<dyn Future<Output = ()>>>>,
+        woken: AtomicBool,
+    }
+
+    // Safety: this executor never actually moves a `Task` across threads; it
+    // only needs `Send + Sync` to satisfy `std::task::Wake`'s bound on `Arc<T>`.
+    unsafe impl Send for Task {}
+    unsafe impl Sync for Task {}
+
+    impl Wake for Task {
+        fn wake(self: Arc<Self>) {
+            self.woken.store(true, Ordering::SeqCst);
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.woken.store(true, Ordering::SeqCst);
+        }
+    }
+
+    // A single-threaded executor that polls at most `polls_per_tick` tasks
+    // per `tick`, sleeping the thread until the next tick once that budget
+    // is exhausted.
+    pub struct ThrottlingExecutor {
+        queue: VecDeque<Arc<Task>>,
+        polls_per_tick: usize,
+        tick: Duration,
+        logger: AsyncLogger,
+    }
+
+    impl ThrottlingExecutor {
+        pub fn new(polls_per_tick: usize, tick: Duration, logger: AsyncLogger) -> Self {
+            ThrottlingExecutor {
+                queue: VecDeque::new(),
+                polls_per_tick,
+                tick,
+                logger,
+            }
+        }
+
+        pub fn spawn<F>(&mut self, future: F)
+        where
+            F: Future<Output = ()> + 'static,
+        {
+            self.queue.push_back(Arc::new(Task {
+                future: RefCell::new(Box::pin(future)),
+                woken: AtomicBool::new(true),
+            }));
+        }
+
+        // Drive every spawned task to completion, throttling poll throughput.
+        pub fn run(&mut self) {
+            let mut polls_this_tick = 0usize;
+            let mut tick_start = Instant::now();
+
+            while let Some(task) = self.queue.pop_front() {
+                if polls_this_tick >= self.polls_per_tick {
+                    let elapsed = tick_start.elapsed();
+                    if elapsed < self.tick {
+                        std::thread::sleep(self.tick - elapsed);
+                    }
+                    self.logger.log(
+                        Level::Debug,
+                        "Executor tick complete",
+                        &[("polled", &polls_this_tick), ("queue_depth", &self.queue.len())],
+                    );
+                    polls_this_tick = 0;
+                    tick_start = Instant::now();
+                }
+
+                if !task.woken.swap(false, Ordering::SeqCst) {
+                    self.queue.push_back(task);
+                    continue;
+                }
+
+                let waker = Waker::from(Arc::clone(&task));
+                let mut cx = Context::from_waker(&waker);
+
+                polls_this_tick += 1;
+                self.logger.log(
+                    Level::Trace,
+                    "Executor polling task",
+                    &[
+                        ("queue_depth", &self.queue.len()),
+                        ("budget_remaining", &(self.polls_per_tick - polls_this_tick)),
+                    ],
+                );
+
+                let poll_result = task.future.borrow_mut().as_mut().poll(&mut cx);
+                match poll_result {
+                    Poll::Ready(()) => {}
+                    Poll::Pending => self.queue.push_back(Arc::clone(&task)),
+                }
+            }
+        }
+    }
+}
+
+use throttling_executor::ThrottlingExecutor;
+
+// A future that stays `Pending` for a fixed number of polls before
+// completing, re-arming its own waker each time. Used to exercise the
+// custom executor without depending on tokio's reactor.
+struct CountdownFuture {
+    remaining: u32,
+}
+
+impl std::future::Future for CountdownFuture {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<()> {
+        if self.remaining == 0 {
+            std::task::Poll::Ready(())
+        } else {
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+}
+
 // Simulated async tasks
 async fn fetch_data(id: u32, logger: AsyncLogger) -> Result<String, &'static str> {
-    logger.log(&format!("Starting fetch_data({})", id));
-    
+    logger.log(Level::Info, "Starting fetch_data", &[("id", &id)]);
+
     // Simulate network delay
-    tokio::time::sleep(Duration::from_millis(id * 100)).await;
-    
+    tokio::time::sleep(Duration::from_millis(id as u64 * 100)).await;
+
     // Simulate occasional failure
     if id % 5 == 0 {
-        logger.log(&format!("fetch_data({}) failed", id));
+        logger.log(Level::Error, "fetch_data failed", &[("id", &id)]);
         return Err("Data fetch failed");
     }
-    
-    logger.log(&format!("fetch_data({}) succeeded", id));
+
+    logger.log(Level::Info, "fetch_data succeeded", &[("id", &id)]);
     Ok(format!("Data for id {}", id))
 }
 
 async fn process_data(data: String, logger: AsyncLogger) -> String {
-    logger.log(&format!("Starting process_data({})", data));
-    
+    logger.log(Level::Info, "Starting process_data", &[("data", &data)]);
+
     // Simulate processing time
     tokio::time::sleep(Duration::from_millis(300)).await;
-    
+
     let result = format!("Processed: {}", data);
-    logger.log(&format!("Finished process_data: {}", result));
+    logger.log(Level::Info, "Finished process_data", &[("result", &result)]);
     result
 }
 
 // Task with a bug (deadlock potential)
 async fn buggy_task(shared_data: Arc<Mutex<Vec<u32>>>, logger: AsyncLogger) {
-    logger.log("Starting buggy_task");
-    
+    logger.log(Level::Info, "Starting buggy_task", &[]);
+
     // Lock the mutex
     let mut data = shared_data.lock().unwrap();
-    logger.log("Acquired lock in buggy_task");
-    
+    logger.log(Level::Debug, "Acquired lock in buggy_task", &[]);
+
     // This await while holding the lock could cause deadlocks in a real app
     // since we're holding the lock across an await point
     tokio::time::sleep(Duration::from_millis(500)).await;
-    
+
     data.push(42);
-    logger.log("Updated shared data and releasing lock");
+    logger.log(Level::Info, "Updated shared data and releasing lock", &[]);
     // Lock is automatically released when data goes out of scope
 }
 
 // Correct task (doesn't hold lock across await points)
 async fn correct_task(shared_data: Arc<Mutex<Vec<u32>>>, logger: AsyncLogger) {
-    logger.log("Starting correct_task");
-    
+    logger.log(Level::Info, "Starting correct_task", &[]);
+
     // Do async work before acquiring the lock
     tokio::time::sleep(Duration::from_millis(500)).await;
-    
+
     // Acquire lock only when needed and release immediately
     {
         let mut data = shared_data.lock().unwrap();
-        logger.log("Acquired lock in correct_task");
+        logger.log(Level::Debug, "Acquired lock in correct_task", &[]);
         data.push(100);
-        logger.log("Updated shared data and releasing lock");
+        logger.log(Level::Info, "Updated shared data and releasing lock", &[]);
     } // Lock is released here
-    
+
     // Continue with more async work if needed
     tokio::time::sleep(Duration::from_millis(200)).await;
-    logger.log("Completed correct_task");
+    logger.log(Level::Info, "Completed correct_task", &[]);
 }
 
 #[tokio::main]
 async fn main() {
     let logger = AsyncLogger::new();
-    logger.log("Starting async debugging demo");
-    
+    logger.log(Level::Info, "Starting async debugging demo", &[]);
+
     // 1. Basic async task debugging
     let fetch_future = debug_future(
         fetch_data(42, logger.clone()),
         "fetch_data",
         logger.clone()
     );
-    
+
     let result = fetch_future.await;
     match result {
         Ok(data) => {
-            logger.log(&format!("Successfully fetched data: {}", data));
-            
+            logger.log(Level::Info, "Successfully fetched data", &[("data", &data)]);
+
             let process_future = debug_future(
                 process_data(data, logger.clone()),
                 "process_data",
                 logger.clone()
             );
-            
+
             let processed = process_future.await;
-            logger.log(&format!("Final result: {}", processed));
+            logger.log(Level::Info, "Final result", &[("result", &processed)]);
         },
-        Err(e) => logger.log(&format!("Error fetching data: {}", e)),
+        Err(e) => logger.log(Level::Error, "Error fetching data", &[("error", &e)]),
     }
-    
+
     // 2. Multiple concurrent tasks
-    logger.log("Starting concurrent tasks");
-    
+    logger.log(Level::Info, "Starting concurrent tasks", &[]);
+
     let shared_data = Arc::new(Mutex::new(Vec::new()));
-    
+
     let task1 = tokio::spawn(buggy_task(Arc::clone(&shared_data), logger.clone()));
     let task2 = tokio::spawn(correct_task(Arc::clone(&shared_data), logger.clone()));
-    
+
     // Wait for both tasks to complete
     let _ = tokio::join!(task1, task2);
-    
+
     // Check final state
     let data = shared_data.lock().unwrap();
-    logger.log(&format!("Final shared data: {:?}", *data));
-    
+    logger.log(Level::Info, "Final shared data", &[("data", &format!("{:?}", *data))]);
+    drop(data);
+
     // 3. Race condition demonstration with tokio tasks
     let counter = Arc::new(Mutex::new(0));
     let mut handles = Vec::new();
-    
+
     for i in 0..5 {
         let counter_clone = Arc::clone(&counter);
         let logger_clone = logger.clone();
         let handle = tokio::spawn(async move {
-            logger_clone.log(&format!("Task {} starting", i));
-            
+            logger_clone.log(Level::Info, "Task starting", &[("task", &i)]);
+
             // Simulate some async work
             tokio::time::sleep(Duration::from_millis(100)).await;
-            
+
             // Update the counter (correctly with a mutex)
             let mut count = counter_clone.lock().unwrap();
             *count += 1;
-            logger_clone.log(&format!("Task {} incremented counter to {}", i, *count));
+            logger_clone.log(Level::Info, "Task incremented counter", &[("task", &i), ("counter", &*count)]);
         });
-        
+
         handles.push(handle);
     }
-    
+
     // Wait for all tasks to complete
     for handle in handles {
         let _ = handle.await;
     }
-    
+
     // Final counter value
     let final_count = *counter.lock().unwrap();
-    logger.log(&format!("Final counter value: {}", final_count));
-    
+    logger.log(Level::Info, "Final counter value", &[("counter", &final_count)]);
+
+    // 4. Drive a few debug futures with the custom throttling executor,
+    // independent of tokio's scheduler, to reproduce scheduling-sensitive
+    // bugs deterministically.
+    let executor_logger = AsyncLogger::new();
+    let mut executor = ThrottlingExecutor::new(2, Duration::from_millis(1), executor_logger.clone());
+    for i in 0..3 {
+        let name: &'static str = match i {
+            0 => "countdown-0",
+            1 => "countdown-1",
+            _ => "countdown-2",
+        };
+        executor.spawn(debug_future(CountdownFuture { remaining: 5 }, name, executor_logger.clone()));
+    }
+    executor.run();
+    executor_logger.dump_log();
+
+    // 5. The same logger works with any `Sink` - switch to JSON Lines for a
+    // machine-parseable record of the same kind of event.
+    let json_logger = AsyncLogger::json();
+    json_logger.log(Level::Info, "Starting JSON sink demo", &[]);
+    let fetch_future = debug_future(
+        fetch_data(7, json_logger.clone()),
+        "fetch_data",
+        json_logger.clone(),
+    );
+    let _ = fetch_future.await;
+    json_logger.log(Level::Info, "Finished JSON sink demo", &[]);
+
     // Dump the execution log
     logger.dump_log();
 }
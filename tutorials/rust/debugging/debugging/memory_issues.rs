@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::rc::Rc;
 use std::cell::RefCell;
 
@@ -68,6 +69,20 @@ fn create_circular_reference() -> Rc<RefCell<Node>> {
     node1
 }
 
+// Builds a plain DAG: two parents (`a` and `b`) both pointing at the same
+// child `c`. No cycle here - `c` just has more than one strong ref - but
+// that shape is exactly what used to trip up a refcount-based detector.
+fn create_shared_child_dag() -> Vec<Rc<RefCell<Node>>> {
+    let a = Rc::new(RefCell::new(Node::new(1)));
+    let b = Rc::new(RefCell::new(Node::new(2)));
+    let c = Rc::new(RefCell::new(Node::new(999)));
+
+    a.borrow_mut().add_child(Rc::clone(&c));
+    b.borrow_mut().add_child(Rc::clone(&c));
+
+    vec![a, b]
+}
+
 // Function with a double-free issue
 fn double_free_example() {
     unsafe {
@@ -109,11 +124,178 @@ fn iterator_invalidation() {
     */
 }
 
+// Report produced by `CycleDetector::scan`: the `Node::value`s making up
+// each detected reference cycle.
+struct CycleReport {
+    cycles: Vec<Vec<i32>>,
+}
+
+impl CycleReport {
+    fn print(&self) {
+        if self.cycles.is_empty() {
+            println!("No reference cycles detected");
+            return;
+        }
+        println!("Detected {} reference cycle(s):", self.cycles.len());
+        for (i, cycle) in self.cycles.iter().enumerate() {
+            println!("  Cycle {}: {:?}", i + 1, cycle);
+        }
+    }
+}
+
+// Mutable bookkeeping threaded through Tarjan's SCC algorithm, grouped so
+// `strongconnect` doesn't need a long parameter list.
+struct TarjanState {
+    indices: HashMap<usize, usize>,
+    low_links: HashMap<usize, usize>,
+    on_stack: HashSet<usize>,
+    stack: Vec<usize>,
+    next_index: usize,
+    cycles: Vec<Vec<i32>>,
+}
+
+impl TarjanState {
+    fn new() -> Self {
+        TarjanState {
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            on_stack: HashSet::new(),
+            stack: Vec::new(),
+            next_index: 0,
+            cycles: Vec::new(),
+        }
+    }
+
+    // Standard Tarjan strongly-connected-components walk. Recurses once per
+    // traversed edge, which is fine for the small graphs these tutorials
+    // build; a real crate walking attacker-controlled or very deep graphs
+    // would want an explicit work stack instead.
+    fn strongconnect(&mut self, ptr: usize, nodes: &HashMap<usize, Rc<RefCell<Node>>>) {
+        self.indices.insert(ptr, self.next_index);
+        self.low_links.insert(ptr, self.next_index);
+        self.next_index += 1;
+        self.stack.push(ptr);
+        self.on_stack.insert(ptr);
+
+        let children = nodes[&ptr].borrow().children.clone();
+        for child in &children {
+            let child_ptr = Rc::as_ptr(child) as usize;
+            if !nodes.contains_key(&child_ptr) {
+                continue;
+            }
+            if !self.indices.contains_key(&child_ptr) {
+                self.strongconnect(child_ptr, nodes);
+                let child_low = self.low_links[&child_ptr];
+                let entry = self.low_links.get_mut(&ptr).unwrap();
+                *entry = (*entry).min(child_low);
+            } else if self.on_stack.contains(&child_ptr) {
+                let child_index = self.indices[&child_ptr];
+                let entry = self.low_links.get_mut(&ptr).unwrap();
+                *entry = (*entry).min(child_index);
+            }
+        }
+
+        // Root of a strongly connected component: pop it off the stack.
+        // A component with more than one node, or a single node with a
+        // self-edge, is a reference cycle - every member keeps every other
+        // member alive forever, independent of any external reference.
+        if self.low_links[&ptr] == self.indices[&ptr] {
+            let mut component = Vec::new();
+            loop {
+                let member = self.stack.pop().unwrap();
+                self.on_stack.remove(&member);
+                component.push(member);
+                if member == ptr {
+                    break;
+                }
+            }
+
+            let has_self_loop = component.len() == 1
+                && nodes[&component[0]]
+                    .borrow()
+                    .children
+                    .iter()
+                    .any(|child| Rc::as_ptr(child) as usize == component[0]);
+
+            if component.len() > 1 || has_self_loop {
+                let mut values: Vec<i32> = component.iter().map(|ptr| nodes[ptr].borrow().value).collect();
+                values.sort_unstable();
+                self.cycles.push(values);
+            }
+        }
+    }
+}
+
+// Finds strong-reference cycles reachable from a set of root nodes.
+//
+// A node's raw `Rc::strong_count` can't tell us this on its own - a shared
+// child in a plain DAG (two parents pointing at the same node, no cycle at
+// all) has the same "extra" strong reference a cyclic back-edge would add.
+// So instead of counting references, this walks the actual `parent -> child`
+// edges and finds strongly connected components (Tarjan's algorithm): any
+// component with more than one node, or a single node pointing at itself, is
+// a genuine reference cycle, independent of how many external handles exist.
+struct CycleDetector;
+
+impl CycleDetector {
+    fn scan(roots: &[Rc<RefCell<Node>>]) -> CycleReport {
+        // Discover every node reachable from the roots, keyed by the Rc's
+        // pointer identity (`Node` has no `Eq`/`Hash` of its own). Each node
+        // is cloned into `nodes` exactly once; the scan never reads
+        // `Rc::strong_count`, so these extra handles don't affect the result.
+        let mut nodes: HashMap<usize, Rc<RefCell<Node>>> = HashMap::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for root in roots {
+            let ptr = Rc::as_ptr(root) as usize;
+            if let Entry::Vacant(entry) = nodes.entry(ptr) {
+                entry.insert(Rc::clone(root));
+                queue.push_back(ptr);
+            }
+        }
+        while let Some(ptr) = queue.pop_front() {
+            let children = nodes[&ptr].borrow().children.clone();
+            for child in children {
+                let child_ptr = Rc::as_ptr(&child) as usize;
+                if let Entry::Vacant(entry) = nodes.entry(child_ptr) {
+                    entry.insert(child);
+                    queue.push_back(child_ptr);
+                }
+            }
+        }
+
+        // Visit every reachable node in a stable order so the result doesn't
+        // depend on `HashMap` iteration order.
+        let mut ptrs: Vec<usize> = nodes.keys().copied().collect();
+        ptrs.sort_unstable();
+
+        let mut tarjan = TarjanState::new();
+        for &start in &ptrs {
+            if !tarjan.indices.contains_key(&start) {
+                tarjan.strongconnect(start, &nodes);
+            }
+        }
+
+        let mut cycles = tarjan.cycles;
+        cycles.sort();
+        CycleReport { cycles }
+    }
+}
+
 fn main() {
     // Memory leak example
-    let _leaked_ref = create_circular_reference();
+    let leaked_ref = create_circular_reference();
     println!("Created circular reference");
-    
+
+    // Confirm the leak the demo above creates
+    let report = CycleDetector::scan(&[Rc::clone(&leaked_ref)]);
+    report.print();
+
+    // A shared child is not a cycle - confirm the scan agrees.
+    let dag_roots = create_shared_child_dag();
+    println!("Created shared-child DAG (no cycle)");
+    let dag_report = CycleDetector::scan(&dag_roots);
+    dag_report.print();
+
     // Use after free example (commented out to prevent UB)
     use_after_free_example();
     